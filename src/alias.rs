@@ -0,0 +1,315 @@
+use crate::Rand;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The error returned when an alias table can't be built from a set of weights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WeightError {
+    /// There are no weights, or more than `u32::MAX`.
+    InvalidLength,
+    /// A weight is negative, NaN or infinite.
+    InvalidWeight,
+    /// The weights add up to zero or overflow.
+    InvalidTotal,
+    /// The buffer is shorter than the weights.
+    BufferTooSmall,
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::InvalidLength => f.write_str("number of weights must be 1 to u32::MAX"),
+            WeightError::InvalidWeight => f.write_str("weights must be finite and non-negative"),
+            WeightError::InvalidTotal => f.write_str("sum of weights must be positive and finite"),
+            WeightError::BufferTooSmall => f.write_str("buffer is shorter than the weights"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeightError {}
+
+/// One column of an alias table, used as the buffer for `AliasTableRef`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AliasEntry {
+    // The column's own index is kept if rand() is below this, out of 2^32
+    threshold: u64,
+    alias: u32,
+}
+
+/// Weighted sampling with Walker's alias method, backed by a caller-supplied buffer.
+///
+/// Building the table takes O(n) time and sampling takes O(1): one `below()` call picks a
+/// column and one `rand()` call picks between the column's index and its alias.
+///
+/// # Example
+///
+/// ```
+/// use msws::{AliasEntry, AliasTableRef, Rand};
+///
+/// let mut buf = [AliasEntry::default(); 3];
+/// let table = AliasTableRef::new(&[1.0, 2.0, 7.0], &mut buf).expect("invalid weights");
+///
+/// let mut r = Rand::stream(0);
+/// let i = table.sample(&mut r);
+/// assert!(i < 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AliasTableRef<'a> {
+    entries: &'a [AliasEntry],
+}
+
+impl<'a> AliasTableRef<'a> {
+    /// Builds an alias table for `weights` in the start of `buf`.
+    pub fn new(weights: &[f64], buf: &'a mut [AliasEntry]) -> Result<Self, WeightError> {
+        if buf.len() < weights.len() {
+            return Err(WeightError::BufferTooSmall);
+        }
+
+        let entries = &mut buf[..weights.len()];
+        build(weights, entries)?;
+
+        Ok(Self { entries })
+    }
+
+    /// Returns a random index into the weights, with probability proportional to its weight.
+    pub fn sample(&self, rand: &mut Rand) -> usize {
+        sample(self.entries, rand)
+    }
+}
+
+/// Weighted sampling with Walker's alias method, enabled with the `alloc` feature.
+///
+/// The same as `AliasTableRef` but owning its buffer.
+///
+/// # Example
+///
+/// ```
+/// use msws::{AliasTable, Rand};
+///
+/// let loot = ["common", "rare", "legendary"];
+/// let table = AliasTable::new(&[90.0, 9.0, 1.0]).expect("invalid weights");
+///
+/// let mut r = Rand::stream(0);
+/// let drop = loot[table.sample(&mut r)];
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasTable {
+    entries: Vec<AliasEntry>,
+}
+
+#[cfg(feature = "alloc")]
+impl AliasTable {
+    /// Builds an alias table for `weights`.
+    pub fn new(weights: &[f64]) -> Result<Self, WeightError> {
+        check_length(weights.len())?;
+
+        let mut entries = alloc::vec![AliasEntry::default(); weights.len()];
+        build(weights, &mut entries)?;
+
+        Ok(Self { entries })
+    }
+
+    /// Returns a random index into the weights, with probability proportional to its weight.
+    pub fn sample(&self, rand: &mut Rand) -> usize {
+        sample(&self.entries, rand)
+    }
+}
+
+fn sample(entries: &[AliasEntry], rand: &mut Rand) -> usize {
+    let i = rand.below(entries.len() as u32) as usize;
+    let entry = entries[i];
+    if (rand.rand() as u64) < entry.threshold {
+        i
+    } else {
+        entry.alias as usize
+    }
+}
+
+fn check_length(n: usize) -> Result<(), WeightError> {
+    if n == 0 || n as u64 > u32::MAX as u64 {
+        return Err(WeightError::InvalidLength);
+    }
+
+    Ok(())
+}
+
+// Builds the table with Vose's algorithm, without extra storage: instead of keeping lists of
+// small and large columns, both are found by scanning forward.
+fn build(weights: &[f64], entries: &mut [AliasEntry]) -> Result<(), WeightError> {
+    let n = weights.len();
+    check_length(n)?;
+
+    let mut total = 0.0;
+    for &w in weights {
+        if !(w.is_finite() && w >= 0.0) {
+            return Err(WeightError::InvalidWeight);
+        }
+        total += w;
+    }
+    if !(total.is_finite() && total > 0.0) {
+        return Err(WeightError::InvalidTotal);
+    }
+
+    // Scale so the average weight is 1. Columns are "small" below 1 and "large" otherwise.
+    // Until a column is finished, `threshold` holds the scaled weight's bits and `alias` is
+    // its own index. Dividing by the total first keeps a subnormal total from overflowing.
+    for (i, (entry, &w)) in entries.iter_mut().zip(weights).enumerate() {
+        entry.threshold = (w / total * n as f64).to_bits();
+        entry.alias = i as u32;
+    }
+    let p = |entries: &[AliasEntry], i: usize| f64::from_bits(entries[i].threshold);
+    let next = |entries: &[AliasEntry], from: usize, small: bool| {
+        (from..n)
+            .find(|&i| (p(entries, i) < 1.0) == small)
+            .unwrap_or(n)
+    };
+
+    let mut scan = next(entries, 0, true);
+    let mut small = scan;
+    let mut large = next(entries, 0, false);
+    while small < n && large < n {
+        // Fill the rest of the small column from the large one
+        let p_small = p(entries, small);
+        let p_large = p(entries, large) + p_small - 1.0;
+        entries[small] = AliasEntry {
+            threshold: (p_small * (1u64 << 32) as f64) as u64,
+            alias: large as u32,
+        };
+        entries[large].threshold = p_large.to_bits();
+
+        if p_large < 1.0 && large < scan {
+            // The large column became small behind the scan, fill it next
+            small = large;
+            large = next(entries, large + 1, false);
+        } else {
+            if p_large < 1.0 {
+                large = next(entries, large + 1, false);
+            }
+            scan = next(entries, scan + 1, true);
+            small = scan;
+        }
+    }
+
+    // Whatever is left is full, up to rounding error
+    for (i, entry) in entries.iter_mut().enumerate() {
+        if entry.alias == i as u32 {
+            entry.threshold = 1 << 32;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the exact probability of each index given by the table.
+    fn probabilities(entries: &[AliasEntry], out: &mut [f64]) {
+        let n = entries.len() as f64;
+        for x in out.iter_mut() {
+            *x = 0.0;
+        }
+        for (i, entry) in entries.iter().enumerate() {
+            let keep = entry.threshold as f64 / (1u64 << 32) as f64;
+            out[i] += keep / n;
+            out[entry.alias as usize] += (1.0 - keep) / n;
+        }
+    }
+
+    fn check(weights: &[f64]) {
+        let mut buf = [AliasEntry::default(); 16];
+        let table = AliasTableRef::new(weights, &mut buf).unwrap();
+        let mut actual = [0.0; 16];
+        probabilities(table.entries, &mut actual[..weights.len()]);
+
+        let total: f64 = weights.iter().sum();
+        for (w, p) in weights.iter().zip(actual.iter()) {
+            assert!((w / total - p).abs() < 1e-9, "{:?} {:?}", weights, actual);
+        }
+    }
+
+    #[test]
+    fn test_build() {
+        check(&[1.0]);
+        check(&[1.0, 1.0]);
+        check(&[1.0, 2.0, 7.0]);
+        check(&[7.0, 2.0, 1.0]);
+        check(&[0.0, 5.0, 0.0, 5.0]);
+        check(&[1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 100.0]);
+        check(&[
+            3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0, 9.0, 7.0, 9.0, 3.0,
+        ]);
+        check(&[1e-300, 1e300]);
+        check(&[1e-310, 0.0]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut buf = [AliasEntry::default(); 2];
+
+        assert_eq!(
+            AliasTableRef::new(&[], &mut buf),
+            Err(WeightError::InvalidLength)
+        );
+        assert_eq!(
+            AliasTableRef::new(&[1.0, -1.0], &mut buf),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            AliasTableRef::new(&[1.0, f64::NAN], &mut buf),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            AliasTableRef::new(&[0.0, 0.0], &mut buf),
+            Err(WeightError::InvalidTotal)
+        );
+        assert_eq!(
+            AliasTableRef::new(&[f64::MAX, f64::MAX], &mut buf),
+            Err(WeightError::InvalidTotal)
+        );
+        assert_eq!(
+            AliasTableRef::new(&[1.0, 1.0, 1.0], &mut buf),
+            Err(WeightError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut buf = [AliasEntry::default(); 4];
+        let table = AliasTableRef::new(&[1.0, 0.0, 2.0, 7.0], &mut buf).unwrap();
+        let mut r = Rand::stream(0);
+
+        let mut counts = [0u32; 4];
+        for _ in 0..100_000 {
+            counts[table.sample(&mut r)] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+        assert!(counts[0] > 9_700 && counts[0] < 10_300, "{:?}", counts);
+        assert!(counts[2] > 19_500 && counts[2] < 20_500, "{:?}", counts);
+        assert!(counts[3] > 69_500 && counts[3] < 70_500, "{:?}", counts);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_alias_table() {
+        let weights = [1.0, 2.0, 7.0];
+        let table = AliasTable::new(&weights).unwrap();
+        let mut buf = [AliasEntry::default(); 3];
+        let table_ref = AliasTableRef::new(&weights, &mut buf).unwrap();
+
+        let mut a = Rand::stream(0);
+        let mut b = Rand::stream(0);
+        for _ in 0..100 {
+            assert_eq!(table.sample(&mut a), table_ref.sample(&mut b));
+        }
+
+        assert_eq!(AliasTable::new(&[]), Err(WeightError::InvalidLength));
+    }
+}
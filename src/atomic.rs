@@ -0,0 +1,130 @@
+use crate::squares::{weyl32, weyl64};
+use crate::{seed, SeedError};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A generator that can be shared between threads without locking.
+///
+/// Only the Weyl sequence is stored, in an `AtomicU64`. Each call claims the next Weyl value
+/// with a single `fetch_add` and derives its output from that value alone. The middle square
+/// state `x` of `Rand` depends on every previous output, so it can't be shared without a lock;
+/// instead `x` is restarted from the claimed Weyl value and put through the rounds of the
+/// Squares generator. The `n`-th claimed value gives `squares::squares32(n, s)`.
+///
+/// Compared with `Rand`:
+///
+/// - Every call claims a different Weyl value, so no two calls (on any thread) get the same
+///   step until the 2^64 period wraps around.
+/// - The outputs are a different stream from `Rand::new(s)`.
+/// - Which thread gets which step depends on scheduling. The set of outputs produced by `n`
+///   calls is deterministic, but the order each thread sees them in isn't.
+/// - Claiming uses `Ordering::Relaxed`: it doesn't synchronise any other memory.
+///
+/// The seed should be a good Weyl constant such as one from `seed()`.
+///
+/// # Example
+///
+/// ```
+/// use msws::AtomicRand;
+///
+/// static RAND: AtomicRand = AtomicRand::stream(0);
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| std::thread::spawn(|| RAND.rand()))
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AtomicRand {
+    // Seed, must be odd
+    s: u64,
+    // Weyl sequence
+    w: AtomicU64,
+}
+
+impl AtomicRand {
+    /// Generates a new AtomicRand struct from an *odd* seed.
+    pub const fn new(s: u64) -> Result<Self, SeedError> {
+        if s & 1 == 0 {
+            return Err(SeedError::EvenSeed);
+        }
+
+        Ok(Self {
+            s,
+            w: AtomicU64::new(0),
+        })
+    }
+
+    /// Generates a new AtomicRand struct using the seed for stream `n`, see `Rand::stream()`.
+    pub const fn stream(n: u64) -> Self {
+        Self {
+            s: seed(n),
+            w: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns a random integer, claiming one step.
+    pub fn rand(&self) -> u32 {
+        weyl32(self.claim(), self.s)
+    }
+
+    /// Returns a random 64-bit integer, claiming one step.
+    pub fn rand_u64(&self) -> u64 {
+        weyl64(self.claim(), self.s)
+    }
+
+    // Returns the current Weyl value and advances it.
+    fn claim(&self) -> u64 {
+        self.w.fetch_add(self.s, Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::squares::{squares32, squares64};
+    use std::sync::Arc;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_rand() {
+        let r = AtomicRand::stream(0);
+        let key = seed(0);
+
+        assert_eq!(r.rand(), squares32(0, key));
+        assert_eq!(r.rand(), squares32(1, key));
+        assert_eq!(r.rand_u64(), squares64(2, key));
+        assert_eq!(r.rand(), 0xb76ba116);
+    }
+
+    #[test]
+    fn test_even_seed() {
+        assert_eq!(AtomicRand::new(2).err(), Some(SeedError::EvenSeed));
+    }
+
+    #[test]
+    fn test_threads() {
+        let r = Arc::new(AtomicRand::stream(0));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let r = Arc::clone(&r);
+                std::thread::spawn(move || (0..1000).map(|_| r.rand()).collect::<Vec<_>>())
+            })
+            .collect();
+
+        let mut outputs: Vec<u32> = handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect();
+        outputs.sort_unstable();
+
+        // The same outputs as 4000 sequential steps, whichever thread claimed them
+        let key = seed(0);
+        let mut expected: Vec<u32> = (0..4000).map(|n| squares32(n, key)).collect();
+        expected.sort_unstable();
+        assert_eq!(outputs, expected);
+    }
+}
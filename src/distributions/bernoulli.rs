@@ -0,0 +1,51 @@
+use super::{check, ParamError};
+use crate::Rand;
+
+/// The Bernoulli distribution: `true` with probability `p`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bernoulli {
+    p: f64,
+}
+
+impl Bernoulli {
+    /// Creates a Bernoulli distribution. `p` must be in `[0, 1]`.
+    pub fn new(p: f64) -> Result<Self, ParamError> {
+        check(p, (0.0..=1.0).contains(&p))?;
+
+        Ok(Self { p })
+    }
+
+    /// Returns a random value from the distribution, using one `next_f64()`.
+    pub fn sample(&self, rand: &mut Rand) -> bool {
+        rand.next_f64() < self.p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Bernoulli::new(0.0).is_ok());
+        assert!(Bernoulli::new(1.0).is_ok());
+        assert_eq!(Bernoulli::new(-0.1), Err(ParamError::OutOfRange));
+        assert_eq!(Bernoulli::new(f64::NAN), Err(ParamError::NotFinite));
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = Rand::stream(0);
+
+        let bernoulli = Bernoulli::new(0.3).unwrap();
+        let hits = (0..100_000).filter(|_| bernoulli.sample(&mut r)).count();
+        assert!(hits > 29_500 && hits < 30_500, "{}", hits);
+
+        let never = Bernoulli::new(0.0).unwrap();
+        let always = Bernoulli::new(1.0).unwrap();
+        for _ in 0..1000 {
+            assert!(!never.sample(&mut r));
+            assert!(always.sample(&mut r));
+        }
+    }
+}
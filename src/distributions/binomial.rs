@@ -0,0 +1,106 @@
+use super::gamma::standard_beta;
+use super::{check, ParamError};
+use crate::Rand;
+
+// Below this many trials the remaining trials are simulated one by one.
+const SMALL_N: u64 = 16;
+
+/// The binomial distribution: the number of successes in `n` trials with probability `p`.
+///
+/// Large `n` is split in half with a beta variate (Knuth, TAOCP 3.4.1) until few enough trials
+/// remain to simulate directly, so sampling is exact and takes O(log n) time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+}
+
+impl Binomial {
+    /// Creates a binomial distribution. `p` must be in `[0, 1]`.
+    pub fn new(n: u64, p: f64) -> Result<Self, ParamError> {
+        check(p, (0.0..=1.0).contains(&p))?;
+
+        Ok(Self { n, p })
+    }
+
+    /// Returns a random value from the distribution.
+    pub fn sample(&self, rand: &mut Rand) -> u64 {
+        binomial(rand, self.n, self.p)
+    }
+}
+
+pub(super) fn binomial(rand: &mut Rand, mut n: u64, mut p: f64) -> u64 {
+    let mut k = 0;
+
+    while n > SMALL_N && p > 0.0 && p < 1.0 {
+        // X is the a-th smallest of n uniform variates
+        let a = 1 + n / 2;
+        let b = n - a + 1;
+        let x = standard_beta(rand, a as f64, b as f64);
+        if x >= p {
+            n = a - 1;
+            p /= x;
+        } else {
+            k += a;
+            n = b - 1;
+            p = (p - x) / (1.0 - x);
+        }
+    }
+
+    if p <= 0.0 {
+        return k;
+    }
+    if p >= 1.0 {
+        return k + n;
+    }
+    for _ in 0..n {
+        if rand.next_f64() < p {
+            k += 1;
+        }
+    }
+
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, moments};
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Binomial::new(10, 0.0).is_ok());
+        assert!(Binomial::new(10, 1.0).is_ok());
+        assert_eq!(Binomial::new(10, 1.5), Err(ParamError::OutOfRange));
+        assert_eq!(Binomial::new(10, f64::NAN), Err(ParamError::NotFinite));
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = Rand::stream(0);
+
+        for &(n, p) in [(10, 0.3), (1000, 0.5), (1_000_000, 0.01)].iter() {
+            let binomial = Binomial::new(n, p).unwrap();
+            let (mean, variance) = moments(20_000, || binomial.sample(&mut r) as f64);
+            let n = n as f64;
+
+            assert_close(mean, n * p, 0.02 * n * p);
+            assert_close(variance, n * p * (1.0 - p), 0.05 * n * p * (1.0 - p));
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let mut r = Rand::stream(0);
+
+        assert_eq!(Binomial::new(0, 0.5).unwrap().sample(&mut r), 0);
+        assert_eq!(Binomial::new(100, 0.0).unwrap().sample(&mut r), 0);
+        assert_eq!(Binomial::new(100, 1.0).unwrap().sample(&mut r), 100);
+        assert_eq!(Binomial::new(u64::MAX, 0.0).unwrap().sample(&mut r), 0);
+        assert_eq!(
+            Binomial::new(u64::MAX, 1.0).unwrap().sample(&mut r),
+            u64::MAX
+        );
+        assert!(Binomial::new(u64::MAX, 0.5).unwrap().sample(&mut r) > 0);
+    }
+}
@@ -0,0 +1,64 @@
+use super::ziggurat_tables::{EXP_F, EXP_R, EXP_X};
+use super::{check, ziggurat, ParamError};
+use crate::Rand;
+
+/// The exponential distribution, sampled with the ziggurat method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exp {
+    lambda_inverse: f64,
+}
+
+impl Exp {
+    /// Creates an exponential distribution with rate `lambda`, which must be positive and not
+    /// so small that `1 / lambda` overflows.
+    pub fn new(lambda: f64) -> Result<Self, ParamError> {
+        let lambda_inverse = 1.0 / lambda;
+        check(lambda, lambda > 0.0 && lambda_inverse.is_finite())?;
+
+        Ok(Self { lambda_inverse })
+    }
+
+    /// Returns a random value from the distribution.
+    pub fn sample(&self, rand: &mut Rand) -> f64 {
+        standard_exp(rand) * self.lambda_inverse
+    }
+}
+
+// Samples the exponential distribution with rate 1.
+pub(super) fn standard_exp(rand: &mut Rand) -> f64 {
+    ziggurat(rand, false, &EXP_X, &EXP_F, pdf, zero_case)
+}
+
+fn pdf(x: f64) -> f64 {
+    libm::exp(-x)
+}
+
+// The tail beyond `EXP_R` is itself exponential.
+fn zero_case(rand: &mut Rand, _u: f64) -> f64 {
+    EXP_R - libm::log(rand.next_f64_open())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, moments};
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Exp::new(1.0).is_ok());
+        assert_eq!(Exp::new(0.0), Err(ParamError::OutOfRange));
+        assert_eq!(Exp::new(1e-310), Err(ParamError::OutOfRange));
+        assert!(Exp::new(f64::MIN_POSITIVE).is_ok());
+        assert_eq!(Exp::new(f64::INFINITY), Err(ParamError::NotFinite));
+    }
+
+    #[test]
+    fn test_sample() {
+        let exp = Exp::new(2.0).unwrap();
+        let mut r = Rand::stream(0);
+        let (mean, variance) = moments(100_000, || exp.sample(&mut r));
+
+        assert_close(mean, 0.5, 0.01);
+        assert_close(variance, 0.25, 0.01);
+    }
+}
@@ -0,0 +1,159 @@
+use super::exponential::standard_exp;
+use super::normal::standard_normal;
+use super::{check, ParamError};
+use crate::Rand;
+
+/// The gamma distribution with a shape and scale, sampled with Marsaglia and Tsang's method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+}
+
+impl Gamma {
+    /// Creates a gamma distribution. `shape` and `scale` must be positive.
+    pub fn new(shape: f64, scale: f64) -> Result<Self, ParamError> {
+        check(shape, shape > 0.0)?;
+        check(scale, scale > 0.0)?;
+
+        Ok(Self { shape, scale })
+    }
+
+    /// Returns a random value from the distribution.
+    pub fn sample(&self, rand: &mut Rand) -> f64 {
+        standard_gamma(rand, self.shape) * self.scale
+    }
+}
+
+/// The beta distribution, sampled as `X / (X + Y)` for gamma variates `X` and `Y`.
+///
+/// The gamma variates are combined in log space, so tiny shapes don't underflow to `0 / 0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta {
+    alpha: f64,
+    beta: f64,
+}
+
+impl Beta {
+    /// Creates a beta distribution. `alpha` and `beta` must be positive.
+    pub fn new(alpha: f64, beta: f64) -> Result<Self, ParamError> {
+        check(alpha, alpha > 0.0)?;
+        check(beta, beta > 0.0)?;
+
+        Ok(Self { alpha, beta })
+    }
+
+    /// Returns a random value from the distribution.
+    pub fn sample(&self, rand: &mut Rand) -> f64 {
+        standard_beta(rand, self.alpha, self.beta)
+    }
+}
+
+// Samples the gamma distribution with scale 1.
+pub(super) fn standard_gamma(rand: &mut Rand, shape: f64) -> f64 {
+    if shape == 1.0 {
+        return standard_exp(rand);
+    }
+    if shape < 1.0 {
+        // Boost to shape + 1 and scale back down with U^(1 / shape)
+        let u = rand.next_f64_open();
+        return standard_gamma(rand, shape + 1.0) * libm::pow(u, 1.0 / shape);
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / libm::sqrt(9.0 * d);
+    loop {
+        let x = standard_normal(rand);
+        let v = 1.0 + c * x;
+        if v <= 0.0 {
+            continue;
+        }
+        let v = v * v * v;
+        let u = rand.next_f64_open();
+        let x_sq = x * x;
+        if u < 1.0 - 0.0331 * x_sq * x_sq
+            || libm::log(u) < 0.5 * x_sq + d * (1.0 - v + libm::log(v))
+        {
+            return d * v;
+        }
+    }
+}
+
+// Samples the logarithm of a gamma variate with scale 1. Shapes below 1 would underflow to 0
+// when scaled by U^(1 / shape), so the scaling is added as ln(U) / shape instead.
+fn ln_standard_gamma(rand: &mut Rand, shape: f64) -> f64 {
+    if shape < 1.0 {
+        let u = rand.next_f64_open();
+        return libm::log(standard_gamma(rand, shape + 1.0)) + libm::log(u) / shape;
+    }
+
+    libm::log(standard_gamma(rand, shape))
+}
+
+// Samples the beta distribution.
+pub(super) fn standard_beta(rand: &mut Rand, alpha: f64, beta: f64) -> f64 {
+    let ln_x = ln_standard_gamma(rand, alpha);
+    let ln_y = ln_standard_gamma(rand, beta);
+
+    1.0 / (1.0 + libm::exp(ln_y - ln_x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, moments};
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Gamma::new(2.0, 1.0).is_ok());
+        assert_eq!(Gamma::new(0.0, 1.0), Err(ParamError::OutOfRange));
+        assert_eq!(Gamma::new(1.0, -1.0), Err(ParamError::OutOfRange));
+        assert!(Beta::new(2.0, 3.0).is_ok());
+        assert_eq!(Beta::new(2.0, 0.0), Err(ParamError::OutOfRange));
+        assert_eq!(Beta::new(f64::NAN, 1.0), Err(ParamError::NotFinite));
+    }
+
+    #[test]
+    fn test_gamma() {
+        let mut r = Rand::stream(0);
+
+        for &(shape, scale) in [(0.5, 2.0), (1.0, 1.0), (3.0, 0.5), (20.0, 1.0)].iter() {
+            let gamma = Gamma::new(shape, scale).unwrap();
+            let (mean, variance) = moments(100_000, || gamma.sample(&mut r));
+
+            assert_close(mean, shape * scale, 0.02 * shape * scale);
+            assert_close(
+                variance,
+                shape * scale * scale,
+                0.04 * shape * scale * scale,
+            );
+        }
+    }
+
+    #[test]
+    fn test_beta() {
+        let beta = Beta::new(2.0, 3.0).unwrap();
+        let mut r = Rand::stream(0);
+        let (mean, variance) = moments(100_000, || beta.sample(&mut r));
+
+        assert_close(mean, 0.4, 0.005);
+        assert_close(variance, 0.04, 0.002);
+    }
+
+    #[test]
+    fn test_beta_tiny_shapes() {
+        let beta = Beta::new(0.001, 0.001).unwrap();
+        let mut r = Rand::stream(0);
+        let mut ones = 0;
+        for _ in 0..100_000 {
+            let x = beta.sample(&mut r);
+            assert!((0.0..=1.0).contains(&x), "{}", x);
+            if x > 0.5 {
+                ones += 1;
+            }
+        }
+
+        // Almost all of the mass is at 0 and 1, split evenly
+        assert!(ones > 49_000 && ones < 51_000, "{}", ones);
+    }
+}
@@ -0,0 +1,61 @@
+use super::{check, ParamError};
+use crate::Rand;
+
+/// The geometric distribution: the number of failures before the first success in trials with
+/// probability `p`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometric {
+    p: f64,
+    // ln(1 - p)
+    ln_q: f64,
+}
+
+impl Geometric {
+    /// Creates a geometric distribution. `p` must be in `(0, 1]`.
+    pub fn new(p: f64) -> Result<Self, ParamError> {
+        check(p, p > 0.0 && p <= 1.0)?;
+
+        Ok(Self {
+            p,
+            ln_q: libm::log1p(-p),
+        })
+    }
+
+    /// Returns a random value from the distribution, by inversion.
+    pub fn sample(&self, rand: &mut Rand) -> u64 {
+        if self.p == 1.0 {
+            return 0;
+        }
+
+        libm::floor(libm::log(rand.next_f64_open()) / self.ln_q) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, moments};
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Geometric::new(1.0).is_ok());
+        assert_eq!(Geometric::new(0.0), Err(ParamError::OutOfRange));
+        assert_eq!(Geometric::new(f64::NAN), Err(ParamError::NotFinite));
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = Rand::stream(0);
+
+        for &p in [0.1, 0.5, 0.9].iter() {
+            let geometric = Geometric::new(p).unwrap();
+            let (mean, variance) = moments(100_000, || geometric.sample(&mut r) as f64);
+            let q = 1.0 - p;
+
+            assert_close(mean, q / p, 0.02 * q / p);
+            assert_close(variance, q / (p * p), 0.05 * q / (p * p));
+        }
+
+        assert_eq!(Geometric::new(1.0).unwrap().sample(&mut r), 0);
+    }
+}
@@ -0,0 +1,142 @@
+//! Non-uniform distributions built on `Rand`, enabled with the `distributions` feature.
+//!
+//! Each distribution is a small struct, validated on construction, with a `sample()` method.
+//! Float functions come from [`libm`][1] so everything works in `no_std`.
+//!
+//! [1]: https://docs.rs/libm
+//!
+//! # Example
+//!
+//! ```
+//! use msws::distributions::Normal;
+//! use msws::Rand;
+//!
+//! let normal = Normal::new(10.0, 2.0).expect("invalid parameters");
+//! let mut r = Rand::stream(0);
+//! normal.sample(&mut r);
+//! ```
+
+mod bernoulli;
+mod binomial;
+mod exponential;
+mod gamma;
+mod geometric;
+mod normal;
+mod poisson;
+mod ziggurat_tables;
+
+pub use bernoulli::Bernoulli;
+pub use binomial::Binomial;
+pub use exponential::Exp;
+pub use gamma::{Beta, Gamma};
+pub use geometric::Geometric;
+pub use normal::Normal;
+pub use poisson::Poisson;
+
+use crate::Rand;
+use core::fmt;
+
+/// The error returned when a distribution's parameters are invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParamError {
+    /// A parameter is NaN or infinite.
+    NotFinite,
+    /// A parameter is outside the range the distribution accepts.
+    OutOfRange,
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::NotFinite => f.write_str("parameter must be finite"),
+            ParamError::OutOfRange => f.write_str("parameter is out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParamError {}
+
+// Checks that `x` is finite and satisfies `valid`.
+fn check(x: f64, valid: bool) -> Result<(), ParamError> {
+    if !x.is_finite() {
+        return Err(ParamError::NotFinite);
+    }
+    if !valid {
+        return Err(ParamError::OutOfRange);
+    }
+
+    Ok(())
+}
+
+// Samples a standard normal or exponential variate using Doornik's variant of the ziggurat.
+//
+// The low 8 bits of one `rand_u64()` output pick the layer and the upper 52 bits give the
+// position within it.
+fn ziggurat(
+    rand: &mut Rand,
+    symmetric: bool,
+    x_tab: &[f64; 257],
+    f_tab: &[f64; 257],
+    pdf: fn(f64) -> f64,
+    zero_case: fn(&mut Rand, f64) -> f64,
+) -> f64 {
+    loop {
+        let bits = rand.rand_u64();
+        let i = (bits & 0xff) as usize;
+
+        // A float in [1, 2) from the upper 52 bits
+        let f = f64::from_bits(0x3ff0_0000_0000_0000 | (bits >> 12));
+        let u = if symmetric { 2.0 * f - 3.0 } else { f - 1.0 };
+        let x = u * x_tab[i];
+
+        let test_x = if symmetric { libm::fabs(x) } else { x };
+        if test_x < x_tab[i + 1] {
+            return x;
+        }
+        if i == 0 {
+            return zero_case(rand, u);
+        }
+        if f_tab[i + 1] + (f_tab[i] - f_tab[i + 1]) * rand.next_f64() < pdf(x) {
+            return x;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns the sample mean and variance of `n` draws.
+    pub(super) fn moments(n: usize, mut sample: impl FnMut() -> f64) -> (f64, f64) {
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        for _ in 0..n {
+            let x = sample();
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / n as f64;
+
+        (mean, sum_sq / n as f64 - mean * mean)
+    }
+
+    pub(super) fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            libm::fabs(actual - expected) <= tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(1.0, true), Ok(()));
+        assert_eq!(check(1.0, false), Err(ParamError::OutOfRange));
+        assert_eq!(check(f64::NAN, true), Err(ParamError::NotFinite));
+        assert_eq!(check(f64::INFINITY, true), Err(ParamError::NotFinite));
+    }
+}
@@ -0,0 +1,88 @@
+use super::ziggurat_tables::{NORM_F, NORM_R, NORM_X};
+use super::{check, ziggurat, ParamError};
+use crate::Rand;
+
+/// The normal (Gaussian) distribution, sampled with the ziggurat method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    /// Creates a normal distribution. `std_dev` must be non-negative.
+    pub fn new(mean: f64, std_dev: f64) -> Result<Self, ParamError> {
+        check(mean, true)?;
+        check(std_dev, std_dev >= 0.0)?;
+
+        Ok(Self { mean, std_dev })
+    }
+
+    /// Returns a random value from the distribution.
+    pub fn sample(&self, rand: &mut Rand) -> f64 {
+        self.mean + self.std_dev * standard_normal(rand)
+    }
+}
+
+// Samples the standard normal distribution.
+pub(super) fn standard_normal(rand: &mut Rand) -> f64 {
+    ziggurat(rand, true, &NORM_X, &NORM_F, pdf, zero_case)
+}
+
+fn pdf(x: f64) -> f64 {
+    libm::exp(-x * x / 2.0)
+}
+
+// Samples the tail beyond `NORM_R` using Marsaglia's method.
+fn zero_case(rand: &mut Rand, u: f64) -> f64 {
+    let mut x = 1.0;
+    let mut y = 0.0;
+    while -2.0 * y < x * x {
+        x = libm::log(rand.next_f64_open()) / NORM_R;
+        y = libm::log(rand.next_f64_open());
+    }
+
+    if u < 0.0 {
+        x - NORM_R
+    } else {
+        NORM_R - x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, moments};
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Normal::new(0.0, 1.0).is_ok());
+        assert!(Normal::new(0.0, 0.0).is_ok());
+        assert_eq!(Normal::new(0.0, -1.0), Err(ParamError::OutOfRange));
+        assert_eq!(Normal::new(f64::NAN, 1.0), Err(ParamError::NotFinite));
+    }
+
+    #[test]
+    fn test_sample() {
+        let normal = Normal::new(10.0, 2.0).unwrap();
+        let mut r = Rand::stream(0);
+        let (mean, variance) = moments(100_000, || normal.sample(&mut r));
+
+        assert_close(mean, 10.0, 0.02);
+        assert_close(variance, 4.0, 0.06);
+    }
+
+    #[test]
+    fn test_tail() {
+        let mut r = Rand::stream(1);
+        let mut tail = 0;
+        for _ in 0..1_000_000 {
+            if libm::fabs(standard_normal(&mut r)) > NORM_R {
+                tail += 1;
+            }
+        }
+
+        // P(|Z| > 3.654) is about 0.000258
+        assert!(tail > 200 && tail < 320, "{}", tail);
+    }
+}
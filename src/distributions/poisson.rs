@@ -0,0 +1,91 @@
+use super::binomial::binomial;
+use super::gamma::standard_gamma;
+use super::{check, ParamError};
+use crate::Rand;
+
+// Below this mean the multiplication method is used directly.
+const SMALL_LAMBDA: f64 = 16.0;
+
+// Above this mean samples could overflow `u64`.
+const MAX_LAMBDA: f64 = (1u64 << 62) as f64;
+
+/// The Poisson distribution with mean `lambda`.
+///
+/// Large means are reduced with gamma and binomial variates (Knuth, TAOCP 3.4.1) and small
+/// ones use the multiplication method, so sampling is exact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Creates a Poisson distribution. `lambda` must be positive and at most 2<sup>62</sup>.
+    pub fn new(lambda: f64) -> Result<Self, ParamError> {
+        check(lambda, lambda > 0.0 && lambda <= MAX_LAMBDA)?;
+
+        Ok(Self { lambda })
+    }
+
+    /// Returns a random value from the distribution.
+    pub fn sample(&self, rand: &mut Rand) -> u64 {
+        let mut lambda = self.lambda;
+        let mut k = 0;
+
+        while lambda > SMALL_LAMBDA {
+            // X is the time of the m-th arrival
+            let m = (lambda * 0.875) as u64;
+            let x = standard_gamma(rand, m as f64);
+            if x >= lambda {
+                return k + binomial(rand, m - 1, lambda / x);
+            }
+            k += m;
+            lambda -= x;
+        }
+
+        let limit = libm::exp(-lambda);
+        let mut product = rand.next_f64();
+        while product > limit {
+            k += 1;
+            product *= rand.next_f64();
+        }
+
+        k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{assert_close, moments};
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Poisson::new(1.0).is_ok());
+        assert_eq!(Poisson::new(0.0), Err(ParamError::OutOfRange));
+        assert_eq!(Poisson::new(1e300), Err(ParamError::OutOfRange));
+        assert_eq!(Poisson::new(f64::INFINITY), Err(ParamError::NotFinite));
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut r = Rand::stream(0);
+
+        for &lambda in [0.5, 4.0, 16.0, 100.0, 1e6].iter() {
+            let poisson = Poisson::new(lambda).unwrap();
+            let (mean, variance) = moments(20_000, || poisson.sample(&mut r) as f64);
+
+            assert_close(mean, lambda, 0.02 * lambda);
+            assert_close(variance, lambda, 0.05 * lambda);
+        }
+    }
+
+    #[test]
+    fn test_max_lambda() {
+        let mut r = Rand::stream(0);
+        let poisson = Poisson::new(MAX_LAMBDA).unwrap();
+
+        for _ in 0..100 {
+            assert_close(poisson.sample(&mut r) as f64, MAX_LAMBDA, 1e-6 * MAX_LAMBDA);
+        }
+    }
+}
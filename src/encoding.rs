@@ -0,0 +1,199 @@
+use crate::{ParseError, Rand, SeedError};
+use core::fmt;
+use core::str::FromStr;
+
+// The version written in the text form, bumped if the layout ever changes.
+const VERSION: &str = "1";
+
+impl Rand {
+    /// Returns the generator's state as bytes: the seed, output and Weyl sequence, each as a
+    /// little-endian `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::new(0xb5ad4eceda1ce2a9).expect("invalid seed");
+    /// let bytes = r.to_bytes();
+    /// let mut restored = Rand::from_bytes(bytes).expect("invalid seed");
+    /// assert_eq!(r.rand(), restored.rand());
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 24] {
+        let mut bytes = [0; 24];
+        bytes[0..8].copy_from_slice(&self.s.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.x.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.w.to_le_bytes());
+        bytes
+    }
+
+    /// Restores a generator from the bytes returned by `to_bytes()`.
+    pub fn from_bytes(bytes: [u8; 24]) -> Result<Self, SeedError> {
+        let word = |i: usize| {
+            let mut b = [0; 8];
+            b.copy_from_slice(&bytes[i..i + 8]);
+            u64::from_le_bytes(b)
+        };
+
+        let mut rand = Rand::new(word(0))?;
+        rand.x = word(8);
+        rand.w = word(16);
+
+        Ok(rand)
+    }
+}
+
+/// Writes the state as `msws1:<s>:<x>:<w>`, each as 16 lowercase hex digits.
+///
+/// ```
+/// use msws::Rand;
+///
+/// let r = Rand::new(0xb5ad4eceda1ce2a9).expect("invalid seed");
+/// let text = r.to_string(); // => "msws1:b5ad4eceda1ce2a9:0000000000000000:0000000000000000"
+/// assert_eq!(text.parse::<Rand>(), Ok(r));
+/// ```
+impl fmt::Display for Rand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "msws{}:{:016x}:{:016x}:{:016x}",
+            VERSION, self.s, self.x, self.w
+        )
+    }
+}
+
+impl FromStr for Rand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut parts = s.split(':');
+        let version = parts
+            .next()
+            .and_then(|tag| tag.strip_prefix("msws"))
+            .ok_or(ParseError::InvalidFormat)?;
+        if version != VERSION {
+            return Err(ParseError::UnknownVersion);
+        }
+
+        let mut word = || {
+            parts
+                .next()
+                .and_then(parse_hex)
+                .ok_or(ParseError::InvalidFormat)
+        };
+        let (s, x, w) = (word()?, word()?, word()?);
+        if parts.next().is_some() {
+            return Err(ParseError::InvalidFormat);
+        }
+
+        let mut rand = Rand::new(s)?;
+        rand.x = x;
+        rand.w = w;
+
+        Ok(rand)
+    }
+}
+
+// Parses exactly 16 hex digits.
+fn parse_hex(s: &str) -> Option<u64> {
+    if s.len() != 16 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u64::from_str_radix(s, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn test_to_bytes() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        r.rand();
+
+        assert_eq!(
+            r.to_bytes(),
+            [
+                0xa9, 0xe2, 0x1c, 0xda, 0xce, 0x4e, 0xad, 0xb5, //
+                0xce, 0x4e, 0xad, 0xb5, 0xa9, 0xe2, 0x1c, 0xda, //
+                0xa9, 0xe2, 0x1c, 0xda, 0xce, 0x4e, 0xad, 0xb5,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        r.rand();
+        let mut restored = Rand::from_bytes(r.to_bytes()).unwrap();
+
+        assert_eq!(restored, r);
+        assert_eq!(restored.rand(), r.rand());
+
+        let mut bytes = r.to_bytes();
+        bytes[0] &= !1;
+        assert_eq!(Rand::from_bytes(bytes), Err(SeedError::EvenSeed));
+    }
+
+    #[test]
+    fn test_display() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        r.rand();
+
+        assert_eq!(
+            r.to_string(),
+            "msws1:b5ad4eceda1ce2a9:da1ce2a9b5ad4ece:b5ad4eceda1ce2a9"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        r.rand();
+
+        assert_eq!(r.to_string().parse(), Ok(r.clone()));
+        assert_eq!(
+            "msws1:B5AD4ECEDA1CE2A9:DA1CE2A9B5AD4ECE:B5AD4ECEDA1CE2A9".parse(),
+            Ok(r)
+        );
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let parse = |s: &str| s.parse::<Rand>().err();
+
+        assert_eq!(
+            parse("msws2:b5ad4eceda1ce2a9:0000000000000000:0000000000000000"),
+            Some(ParseError::UnknownVersion)
+        );
+        assert_eq!(
+            parse("msws1:b5ad4eceda1ce2a8:0000000000000000:0000000000000000"),
+            Some(ParseError::InvalidSeed(SeedError::EvenSeed))
+        );
+        assert_eq!(parse(""), Some(ParseError::InvalidFormat));
+        assert_eq!(
+            parse("rand1:b5ad4eceda1ce2a9:0000000000000000:0000000000000000"),
+            Some(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("msws1:b5ad4eceda1ce2a9:0000000000000000"),
+            Some(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("msws1:b5ad4eceda1ce2a9:0000000000000000:0000000000000000:0"),
+            Some(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("msws1:b5ad4eceda1ce2a9:0:0000000000000000"),
+            Some(ParseError::InvalidFormat)
+        );
+        assert_eq!(
+            parse("msws1:+5ad4eceda1ce2a9:0000000000000000:0000000000000000"),
+            Some(ParseError::InvalidFormat)
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! Seeding from OS entropy, enabled with the `getrandom` feature.
+
+use crate::Rand;
+
+impl Rand {
+    /// Generates a new Rand struct from OS entropy.
+    ///
+    /// 8 bytes from [`getrandom`][1] are read as a little-endian integer and passed to
+    /// `Rand::stream()`, so the Weyl constant is built by `seed()`.
+    ///
+    /// [1]: https://docs.rs/getrandom
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::from_entropy().expect("no entropy");
+    /// r.rand();
+    /// ```
+    pub fn from_entropy() -> Result<Self, getrandom::Error> {
+        let mut bytes = [0; 8];
+        getrandom::getrandom(&mut bytes)?;
+
+        Ok(Rand::stream(u64::from_le_bytes(bytes)))
+    }
+
+    /// Generates a new Rand struct from OS entropy, or from stream `fallback` if none is
+    /// available.
+    pub fn from_entropy_or(fallback: u64) -> Self {
+        Self::from_entropy().unwrap_or_else(|_| Rand::stream(fallback))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate_seed;
+
+    #[test]
+    fn test_from_entropy() {
+        let a = Rand::from_entropy().unwrap();
+        let b = Rand::from_entropy().unwrap();
+
+        assert_eq!(validate_seed(a.s), Ok(()));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_from_entropy_or() {
+        let r = Rand::from_entropy_or(0);
+        assert_eq!(validate_seed(r.s), Ok(()));
+    }
+}
@@ -0,0 +1,84 @@
+use core::fmt;
+
+/// The error returned when a seed can't be used to create a generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SeedError {
+    /// The seed is even. Weyl constants must be odd.
+    EvenSeed,
+    /// The seed is odd but doesn't have enough different hex digits, see `validate_seed()`.
+    WeakSeed,
+}
+
+impl fmt::Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeedError::EvenSeed => f.write_str("seed must be odd"),
+            SeedError::WeakSeed => {
+                f.write_str("seed must have at least 7 different hex digits in each 32-bit half")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SeedError {}
+
+/// The error returned when parsing a `Rand` from its text form fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The text isn't of the form `msws<version>:<s>:<x>:<w>`.
+    InvalidFormat,
+    /// The version tag isn't one this version of the crate can read.
+    UnknownVersion,
+    /// The state was read but the seed is invalid.
+    InvalidSeed(SeedError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidFormat => f.write_str("invalid format, expected msws1:<s>:<x>:<w>"),
+            ParseError::UnknownVersion => f.write_str("unknown version"),
+            ParseError::InvalidSeed(e) => write!(f, "invalid seed: {}", e),
+        }
+    }
+}
+
+impl From<SeedError> for ParseError {
+    fn from(e: SeedError) -> Self {
+        ParseError::InvalidSeed(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::InvalidSeed(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(SeedError::EvenSeed.to_string(), "seed must be odd");
+        assert_eq!(
+            SeedError::WeakSeed.to_string(),
+            "seed must have at least 7 different hex digits in each 32-bit half"
+        );
+        assert_eq!(
+            ParseError::InvalidSeed(SeedError::EvenSeed).to_string(),
+            "invalid seed: seed must be odd"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::Rand;
+
+impl Rand {
+    /// Fills `dest` with random bytes.
+    ///
+    /// Each `rand()` output is written as 4 little-endian bytes, so the result is the same on
+    /// every platform. If the length isn't a multiple of 4 the last output provides the
+    /// remaining bytes from its low end and the rest is discarded, so exactly
+    /// `dest.len().div_ceil(4)` outputs are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::new(0xb5ad4eceda1ce2a9).expect("invalid seed");
+    /// let mut buf = [0; 6];
+    /// r.fill_bytes(&mut buf); // => [0xce, 0x4e, 0xad, 0xb5, 0x5c, 0xe8]
+    /// ```
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.rand().to_le_bytes());
+        }
+
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            let bytes = self.rand().to_le_bytes();
+            tail.copy_from_slice(&bytes[..tail.len()]);
+        }
+    }
+
+    /// Fills `dest` with `rand()` outputs.
+    pub fn fill_u32(&mut self, dest: &mut [u32]) {
+        for n in dest {
+            *n = self.rand();
+        }
+    }
+
+    /// Fills `dest` with `rand_u64()` outputs.
+    pub fn fill_u64(&mut self, dest: &mut [u64]) {
+        for n in dest {
+            *n = self.rand_u64();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_bytes() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut buf = [0; 8];
+        r.fill_bytes(&mut buf);
+
+        assert_eq!(buf, [0xce, 0x4e, 0xad, 0xb5, 0x5c, 0xe8, 0x4e, 0xdf]);
+    }
+
+    #[test]
+    fn test_fill_bytes_tail() {
+        for len in 0..=8 {
+            let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+            let mut buf = [0; 8];
+            r.fill_bytes(&mut buf[..len]);
+
+            let expected = [0xce, 0x4e, 0xad, 0xb5, 0x5c, 0xe8, 0x4e, 0xdf];
+            assert_eq!(buf[..len], expected[..len]);
+            assert!(buf[len..].iter().all(|b| *b == 0));
+
+            // One output is used per started 4 bytes
+            let mut other = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+            other.discard((len as u64).div_ceil(4));
+            assert_eq!(r.rand(), other.rand());
+        }
+    }
+
+    #[test]
+    fn test_fill_u32() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut buf = [0; 3];
+        r.fill_u32(&mut buf);
+
+        assert_eq!(buf, [0xb5ad4ece, 0xdf4ee85c, 0x1889155f]);
+    }
+
+    #[test]
+    fn test_fill_u64() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut buf = [0; 2];
+        r.fill_u64(&mut buf);
+
+        assert_eq!(buf, [0xdf4ee85c_b5ad4ece, 0xc6dcbccf_1889155f]);
+    }
+}
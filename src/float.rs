@@ -0,0 +1,163 @@
+use crate::Rand;
+
+// 2^-24 and 2^-53, the spacing of floats with 24 and 53 bit mantissas in [0, 1).
+const F32_EPSILON: f32 = 1.0 / (1u32 << 24) as f32;
+const F64_EPSILON: f64 = 1.0 / (1u64 << 53) as f64;
+
+impl Rand {
+    /// Returns a random float in `[0, 1)`.
+    ///
+    /// Built from the upper 24 bits of one `rand()` output as `(rand() >> 8) * 2^-24`, every
+    /// value is a multiple of 2^-24 and the result is exact on every platform.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.rand() >> 8) as f32 * F32_EPSILON
+    }
+
+    /// Returns a random float in `(0, 1]`, as `((rand() >> 8) + 1) * 2^-24`.
+    pub fn next_f32_open_closed(&mut self) -> f32 {
+        ((self.rand() >> 8) + 1) as f32 * F32_EPSILON
+    }
+
+    /// Returns a random float in `(0, 1)`, as `((rand() >> 9) * 2 + 1) * 2^-24`.
+    pub fn next_f32_open(&mut self) -> f32 {
+        ((self.rand() >> 9) * 2 + 1) as f32 * F32_EPSILON
+    }
+
+    /// Returns a random float in `[0, 1)`.
+    ///
+    /// Built from the upper 53 bits of one `rand_u64()` output (two `rand()` calls) as
+    /// `(rand_u64() >> 11) * 2^-53`, every value is a multiple of 2^-53 and the result is exact
+    /// on every platform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::new(0xb5ad4eceda1ce2a9).expect("invalid seed");
+    /// r.next_f64(); // => 0.8722977854101163
+    /// ```
+    pub fn next_f64(&mut self) -> f64 {
+        (self.rand_u64() >> 11) as f64 * F64_EPSILON
+    }
+
+    /// Returns a random float in `(0, 1]`, as `((rand_u64() >> 11) + 1) * 2^-53`.
+    pub fn next_f64_open_closed(&mut self) -> f64 {
+        ((self.rand_u64() >> 11) + 1) as f64 * F64_EPSILON
+    }
+
+    /// Returns a random float in `(0, 1)`, as `((rand_u64() >> 12) * 2 + 1) * 2^-53`.
+    pub fn next_f64_open(&mut self) -> f64 {
+        ((self.rand_u64() >> 12) * 2 + 1) as f64 * F64_EPSILON
+    }
+
+    /// Returns a random float in `[lo, hi)`, as `lo * (1 - u) + hi * u` where `u = next_f64()`.
+    ///
+    /// This can't overflow, even for `range_f64(f64::MIN, f64::MAX)`. Results rounded out of the
+    /// range are discarded and drawn again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo >= hi` or either bound isn't finite.
+    pub fn range_f64(&mut self, lo: f64, hi: f64) -> f64 {
+        assert!(lo < hi, "cannot sample empty range");
+        assert!(lo.is_finite() && hi.is_finite(), "range must be finite");
+
+        loop {
+            let u = self.next_f64();
+            let f = lo * (1.0 - u) + hi * u;
+            if (lo..hi).contains(&f) {
+                return f;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_f32() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        assert_eq!(r.next_f32(), (0xb5ad4e as f32) / 16777216.0);
+        assert_eq!(r.next_f32_open_closed(), (0xdf4ee9 as f32) / 16777216.0);
+        assert_eq!(
+            r.next_f32_open(),
+            ((0x1889155f_u32 >> 9) * 2 + 1) as f32 / 16777216.0
+        );
+    }
+
+    #[test]
+    fn test_next_f64() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        assert_eq!(r.next_f64(), 0.8722977854101163);
+        assert_eq!(
+            r.next_f64_open_closed(),
+            ((0xc6dcbccf_1889155f_u64 >> 11) + 1) as f64 / 9007199254740992.0
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        for _ in 0..10_000 {
+            let f = r.next_f32();
+            assert!((0.0..1.0).contains(&f));
+            let f = r.next_f32_open_closed();
+            assert!(f > 0.0 && f <= 1.0);
+            let f = r.next_f32_open();
+            assert!(f > 0.0 && f < 1.0);
+            let f = r.next_f64();
+            assert!((0.0..1.0).contains(&f));
+            let f = r.next_f64_open_closed();
+            assert!(f > 0.0 && f <= 1.0);
+            let f = r.next_f64_open();
+            assert!(f > 0.0 && f < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_eq!(0 as f32 * F32_EPSILON, 0.0);
+        assert_eq!((0xffffff + 1) as f32 * F32_EPSILON, 1.0);
+        assert!(((0x7fffff * 2 + 1) as f32 * F32_EPSILON) < 1.0);
+        assert_eq!(((1u64 << 53) as f64) * F64_EPSILON, 1.0);
+        assert!((((1u64 << 52) - 1) * 2 + 1) as f64 * F64_EPSILON < 1.0);
+    }
+
+    #[test]
+    fn test_range_f64() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        for _ in 0..10_000 {
+            let f = r.range_f64(-2.5, 7.0);
+            assert!((-2.5..7.0).contains(&f));
+        }
+    }
+
+    #[test]
+    fn test_range_f64_extremes() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        for _ in 0..10_000 {
+            let f = r.range_f64(f64::MIN, f64::MAX);
+            assert!((f64::MIN..f64::MAX).contains(&f));
+        }
+
+        // Half of the draws round to `hi` and are discarded
+        let hi = 1.0 + f64::EPSILON;
+        for _ in 0..100 {
+            assert_eq!(r.range_f64(1.0, hi), 1.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_f64_empty() {
+        Rand::new(0xb5ad4eceda1ce2a9).unwrap().range_f64(1.0, 1.0);
+    }
+}
@@ -0,0 +1,97 @@
+//! An interrupt-safe global generator, enabled with the `critical-section` feature.
+
+use crate::{Rand, SeedError};
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+/// A `Rand` that can be stored in a `static` and shared between the main loop and interrupt
+/// handlers.
+///
+/// Every access runs inside a [`critical_section`][1], so it works on single-core targets
+/// without `std`. The binary has to provide a critical section implementation, for example
+/// from `cortex-m` with its `critical-section-single-core` feature.
+///
+/// [1]: https://docs.rs/critical-section
+///
+/// # Example
+///
+/// ```
+/// use msws::GlobalRand;
+///
+/// static GLOBAL: GlobalRand = GlobalRand::new();
+///
+/// GLOBAL.init(msws::seed(0)).expect("invalid seed");
+/// GLOBAL.next_u32(); // => Some(0x8b5ad4ce)
+/// ```
+#[derive(Debug)]
+pub struct GlobalRand {
+    rand: Mutex<RefCell<Option<Rand>>>,
+}
+
+impl GlobalRand {
+    /// Creates an uninitialised GlobalRand, call `init()` before use.
+    pub const fn new() -> Self {
+        Self {
+            rand: Mutex::new(RefCell::new(None)),
+        }
+    }
+
+    /// Initialises, or reinitialises, the generator from an *odd* seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from inside `with()`.
+    pub fn init(&self, s: u64) -> Result<(), SeedError> {
+        let rand = Rand::new(s)?;
+        critical_section::with(|cs| *self.rand.borrow(cs).borrow_mut() = Some(rand));
+
+        Ok(())
+    }
+
+    /// Returns a random integer, or `None` if the generator isn't available, see `with()`.
+    pub fn next_u32(&self) -> Option<u32> {
+        self.with(|rand| rand.rand())
+    }
+
+    /// Runs `f` with the generator inside a critical section, or returns `None` if `init()`
+    /// hasn't been called or the generator is already in use by an outer call to `with()`.
+    ///
+    /// Keep `f` short: interrupts are disabled while it runs.
+    pub fn with<T>(&self, f: impl FnOnce(&mut Rand) -> T) -> Option<T> {
+        critical_section::with(|cs| match self.rand.borrow(cs).try_borrow_mut() {
+            Ok(mut rand) => rand.as_mut().map(f),
+            Err(_) => None,
+        })
+    }
+}
+
+impl Default for GlobalRand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_global() {
+        static GLOBAL: GlobalRand = GlobalRand::new();
+
+        assert_eq!(GLOBAL.next_u32(), None);
+        assert_eq!(GLOBAL.init(2), Err(SeedError::EvenSeed));
+        assert_eq!(GLOBAL.next_u32(), None);
+
+        GLOBAL.init(0xb5ad4eceda1ce2a9).unwrap();
+        assert_eq!(GLOBAL.next_u32(), Some(0xb5ad4ece));
+        assert_eq!(GLOBAL.with(|r| r.below(6)), Some(5));
+
+        // Re-entrant calls don't get the generator
+        assert_eq!(GLOBAL.with(|_| GLOBAL.next_u32()), Some(None));
+
+        // Reinitialising starts again
+        GLOBAL.init(0xb5ad4eceda1ce2a9).unwrap();
+        assert_eq!(GLOBAL.next_u32(), Some(0xb5ad4ece));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Rand;
+use core::iter::FusedIterator;
+
+/// An infinite iterator of random 32-bit integers, created by `Rand::iter()`.
+pub struct Iter<'a> {
+    rand: &'a mut Rand,
+}
+
+/// An infinite iterator of random 64-bit integers, created by `Rand::iter_u64()`.
+pub struct IterU64<'a> {
+    rand: &'a mut Rand,
+}
+
+impl Rand {
+    /// Returns an infinite iterator of `rand()` outputs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::new(0xb5ad4eceda1ce2a9).expect("invalid seed");
+    /// let v: Vec<u32> = r.iter().take(2).collect(); // => [0xb5ad4ece, 0xdf4ee85c]
+    /// ```
+    pub fn iter(&mut self) -> Iter<'_> {
+        Iter { rand: self }
+    }
+
+    /// Returns an infinite iterator of `rand_u64()` outputs.
+    pub fn iter_u64(&mut self) -> IterU64<'_> {
+        IterU64 { rand: self }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        Some(self.rand.rand())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for Iter<'_> {}
+
+impl Iterator for IterU64<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        Some(self.rand.rand_u64())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for IterU64<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut v = [0; 3];
+        for (a, b) in v.iter_mut().zip(r.iter()) {
+            *a = b;
+        }
+
+        assert_eq!(v, [0xb5ad4ece, 0xdf4ee85c, 0x1889155f]);
+        // The iterator borrows the generator, which carries on afterwards
+        assert_eq!(r.rand(), 0xc6dcbccf);
+    }
+
+    #[test]
+    fn test_iter_u64() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut it = r.iter_u64();
+
+        assert_eq!(it.next(), Some(0xdf4ee85c_b5ad4ece));
+        assert_eq!(it.next(), Some(0xc6dcbccf_1889155f));
+        assert_eq!(it.size_hint(), (usize::MAX, None));
+    }
+}
@@ -15,6 +15,42 @@
 //! r.rand(); // => 0xb5ad4ece
 //! ```
 //!
+//! Construction, generation and seeding are `const fn`, so tables can be generated at compile
+//! time:
+//!
+//! ```
+//! const TABLE: [u32; 4] = msws::const_fill(msws::seed(0));
+//!
+//! const R: msws::Rand = msws::Rand::stream(0);
+//! ```
+//!
+//! # Variants
+//!
+//! - `Rand64`: two interleaved generators producing 64 bits per call (`msws64`).
+//! - `Rand128`: 128-bit state producing 64 bits per call (`msws128`), seeded with `seed128()`.
+//! - `squares`: the stateless counter-based Squares generator, for random access into a stream.
+//! - `AtomicRand`: a lock-free generator that can be shared between threads, built on `squares`.
+//!
+//! # Features
+//!
+//! - `std`: adds a thread-local generator with free functions such as `random_u32()`, and
+//!   implements `std::error::Error` for the error types. Enables `alloc`.
+//! - `alloc`: adds `AliasTable`, an owning version of `AliasTableRef`, and the `Vec` returning
+//!   functions in `sample`.
+//! - `distributions`: the `distributions` module of non-uniform distributions (normal,
+//!   exponential, gamma, beta, binomial, Poisson, geometric and Bernoulli), using `libm`.
+//! - `critical-section`: adds `GlobalRand`, a generator that can be shared with interrupt
+//!   handlers on embedded targets.
+//! - `getrandom`: adds `Rand::from_entropy()` and `Rand::from_entropy_or()`, seeded from OS
+//!   entropy.
+//! - `serde`: implements `Serialize` and `Deserialize` for `Rand`, so its state can be saved
+//!   and restored. Deserializing checks that the seed is odd.
+//! - `rand_core`: implements [`RngCore`][2] and [`SeedableRng`][3] for `Rand`, so it can be
+//!   used with the `rand` ecosystem. Seeds are passed through `seed()`.
+//!
+//! [2]: https://docs.rs/rand_core/0.6/rand_core/trait.RngCore.html
+//! [3]: https://docs.rs/rand_core/0.6/rand_core/trait.SeedableRng.html
+//!
 //! # Crypto
 //!
 //! Pseudorandom number generators should not be used for crypto.
@@ -22,10 +58,62 @@
 #![deny(missing_docs)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::result::Result;
 
+mod alias;
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+#[cfg(feature = "distributions")]
+pub mod distributions;
+mod encoding;
+#[cfg(feature = "getrandom")]
+mod entropy;
+mod error;
+mod fill;
+mod float;
+#[cfg(feature = "critical-section")]
+mod global;
+mod iter;
+mod rand128;
+mod rand64;
+mod range;
+pub mod sample;
+pub mod seedgen;
+mod seq;
+pub mod squares;
+#[cfg(feature = "std")]
+mod thread;
+
+#[cfg(feature = "rand_core")]
+mod rand_core_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "alloc")]
+pub use alias::AliasTable;
+pub use alias::{AliasEntry, AliasTableRef, WeightError};
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicRand;
+pub use error::{ParseError, SeedError};
+#[cfg(feature = "critical-section")]
+pub use global::GlobalRand;
+pub use iter::{Iter, IterU64};
+pub use rand128::Rand128;
+pub use rand64::Rand64;
+pub use range::SampleRange;
+#[cfg(feature = "std")]
+pub use thread::{random_f64, random_range, random_u32, random_u64, reseed_thread_rng, shuffle};
+
 /// This struct holds the state necessary to generate random numbers.
 /// You should continue to call `rand()` on the same instance of the struct.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "serde_impl::RandState"))]
 pub struct Rand {
     // Seed, must be odd
     s: u64,
@@ -37,16 +125,101 @@ pub struct Rand {
 
 impl Rand {
     /// Generates a new Rand struct from an *odd* seed.
-    pub fn new(s: u64) -> Result<Self, &'static str> {
+    pub const fn new(s: u64) -> Result<Self, SeedError> {
         if s & 1 == 0 {
-            return Err("seed must be odd");
+            return Err(SeedError::EvenSeed);
         }
 
         Ok(Self { s, x: 0, w: 0 })
     }
 
+    /// Generates a new Rand struct from a seed that passes `validate_seed()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::{Rand, SeedError};
+    ///
+    /// assert!(Rand::new_checked(0xb5ad4eceda1ce2a9).is_ok());
+    /// assert_eq!(Rand::new_checked(1).err(), Some(SeedError::WeakSeed));
+    /// ```
+    pub const fn new_checked(s: u64) -> Result<Self, SeedError> {
+        match validate_seed(s) {
+            Ok(()) => Self::new(s),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Generates a new Rand struct for stream `n`.
+    ///
+    /// Each stream uses the Weyl constant from `seed(n)`. Streams below 3,000,000,000 get distinct
+    /// constants; larger `n` can share a constant with another stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::stream(0);
+    /// r.rand(); // => 0x8b5ad4ce
+    /// ```
+    pub const fn stream(n: u64) -> Self {
+        Self {
+            s: seed(n),
+            x: 0,
+            w: 0,
+        }
+    }
+
+    /// Generates a new Rand struct from arbitrary bytes.
+    ///
+    /// The bytes are hashed with 64-bit [FNV-1a][1] and the hash is passed to `Rand::stream()`.
+    /// The mapping is part of the stable API and won't change between versions.
+    ///
+    /// [1]: http://www.isthe.com/chongo/tech/comp/fnv/index.html#FNV-1a
+    pub const fn from_bytes_seed(bytes: &[u8]) -> Self {
+        Self::stream(fnv1a(bytes))
+    }
+
+    /// Generates a new Rand struct from a string, by hashing its UTF-8 bytes with
+    /// `from_bytes_seed()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::from_str_seed("checkout-flow/regression-17");
+    /// r.rand(); // => 0xdf6205bc
+    /// ```
+    pub const fn from_str_seed(s: &str) -> Self {
+        Self::from_bytes_seed(s.as_bytes())
+    }
+
+    /// Returns a child generator with a Weyl constant derived from this generator's output.
+    ///
+    /// The child's constant is built the same way as `seed()`, so it is always valid. Splitting
+    /// is deterministic: the same parent state always produces the same children.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut parent = Rand::stream(0);
+    /// let mut workers: Vec<Rand> = (0..4).map(|_| parent.split()).collect();
+    /// workers[0].rand();
+    /// ```
+    pub const fn split(&mut self) -> Rand {
+        Rand {
+            s: weyl_constant(self),
+            x: 0,
+            w: 0,
+        }
+    }
+
     /// Returns a random integer.
-    pub fn rand(&mut self) -> u32 {
+    pub const fn rand(&mut self) -> u32 {
         // Square the number
         self.x = self.x.wrapping_pow(2);
 
@@ -57,13 +230,58 @@ impl Rand {
         self.x = self.x.wrapping_add(self.w);
 
         // Store the middle 32-bits
-        self.x = (self.x >> 32) | (self.x << 32);
+        self.x = self.x.rotate_left(32);
 
         self.x as u32
     }
+
+    /// Returns a random 64-bit integer from two calls to `rand()`, the first giving the low
+    /// 32 bits.
+    pub const fn rand_u64(&mut self) -> u64 {
+        let lo = self.rand() as u64;
+        let hi = self.rand() as u64;
+
+        (hi << 32) | lo
+    }
+
+    /// Advances the generator by `n` steps, discarding the output.
+    ///
+    /// This is exactly equivalent to calling `rand()` `n` times. Only the Weyl sequence `w`
+    /// advances linearly, the middle square state `x` depends on every previous step so it
+    /// has to be stepped through, making this O(n). Use `jump()` for an O(1) alternative.
+    pub fn discard(&mut self, n: u64) {
+        for _ in 0..n {
+            self.rand();
+        }
+    }
+
+    /// Moves the Weyl sequence forward by `n` steps in O(1) and restarts the middle square
+    /// state from it.
+    ///
+    /// The Weyl sequence ends up exactly where `n` calls to `rand()` would leave it, but `x`
+    /// cannot be reconstructed without stepping, so it is set to `w`. This is how `seed()`
+    /// positions its generator, and gives a stream that is *not* the same as calling
+    /// `discard(n)`. Generators with the same seed that are jumped to distinct positions
+    /// visit distinct Weyl states until their chunks overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::{seed, Rand};
+    ///
+    /// // Split one stream into chunks of a million outputs per worker
+    /// let mut worker = Rand::new(seed(0)).expect("invalid seed");
+    /// worker.jump(3 * 1_000_000);
+    /// worker.rand();
+    /// ```
+    pub const fn jump(&mut self, n: u64) {
+        self.w = self.w.wrapping_add(n.wrapping_mul(self.s));
+        self.x = self.w;
+    }
 }
 
-// Each value provides 100 million unique outputs.
+// Each value provides 100 million unique outputs. Both halves of each value are outputs of
+// `different_digits()` from a generator seeded with `seedgen::BASE`, see `seedgen`.
 const S: [u64; 30] = [
     0x8b5ad4ce914ecdf7,
     0xdbc8915f4b1cd961,
@@ -97,6 +315,19 @@ const S: [u64; 30] = [
     0x49a180de9567182d,
 ];
 
+// 64-bit FNV-1a.
+const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+
+    hash
+}
+
 /// Returns a seed for a given integer.
 ///
 /// # Example
@@ -105,21 +336,117 @@ const S: [u64; 30] = [
 /// use msws::seed;
 /// seed(0); // => 0x8b5ad4ceb9c1fe73
 /// ```
-pub fn seed(n: u64) -> u64 {
-    let mut rand = {
-        let mut r: u64 = n / 100_000_000;
-        let t: u64 = n % 100_000_000;
-        let s = S[r as usize % 30];
-        r /= 30;
-        let w = t.wrapping_mul(s) + r.wrapping_mul(s).wrapping_mul(100_000_000);
-        let x = w;
-        Rand { s, x, w }
+pub const fn seed(n: u64) -> u64 {
+    weyl_constant(&mut seed_rand(&S, n))
+}
+
+/// Returns a 128-bit seed for a given integer, for use with `Rand128`.
+///
+/// # Example
+///
+/// ```
+/// use msws::seed128;
+/// seed128(0); // => 0x8b5ad4ceb9c1fe73648b2ae1f31c2e09
+/// ```
+pub const fn seed128(n: u64) -> u128 {
+    let mut rand = seed_rand(&S, n);
+
+    ((different_digits(&mut rand) as u128) << 96)
+        | ((different_digits(&mut rand) as u128) << 64)
+        | ((different_digits(&mut rand) as u128) << 32)
+        | (different_digits(&mut rand) as u128)
+        | 1
+}
+
+/// Returns an array of `rand()` outputs from a generator with the given *odd* seed, for use in
+/// `const` contexts.
+///
+/// # Panics
+///
+/// Panics (at compile time, when used in a `const`) if the seed is even.
+///
+/// # Example
+///
+/// ```
+/// const TABLE: [u32; 256] = msws::const_fill(msws::seed(42));
+///
+/// let mut r = msws::Rand::new(msws::seed(42)).expect("invalid seed");
+/// assert_eq!(TABLE[0], r.rand());
+/// ```
+pub const fn const_fill<const N: usize>(seed: u64) -> [u32; N] {
+    let mut rand = match Rand::new(seed) {
+        Ok(rand) => rand,
+        Err(_) => panic!("seed must be odd"),
     };
 
-    ((different_digits(&mut rand) as u64) << 32) | (different_digits(&mut rand) as u64) | 1
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = rand.rand();
+        i += 1;
+    }
+
+    out
 }
 
-fn different_digits(rand: &mut Rand) -> u32 {
+/// Checks that a seed is a good Weyl constant.
+///
+/// Following Widynski, each 32-bit half should have different hex digits. At most one digit
+/// may repeat in each half, which allows for the lowest bit having been set to make the seed
+/// odd. This rules out constants with runs of repeated digits and keeps the number of one bits
+/// between 16 and 48. Every seed returned by `seed()` passes.
+///
+/// # Example
+///
+/// ```
+/// use msws::{validate_seed, SeedError};
+///
+/// assert_eq!(validate_seed(0xb5ad4eceda1ce2a9), Ok(()));
+/// assert_eq!(validate_seed(0xb5ad4eceda1ce2a8), Err(SeedError::EvenSeed));
+/// assert_eq!(validate_seed(0x0000000000000001), Err(SeedError::WeakSeed));
+/// ```
+pub const fn validate_seed(s: u64) -> Result<(), SeedError> {
+    if s & 1 == 0 {
+        return Err(SeedError::EvenSeed);
+    }
+
+    if count_different_digits((s >> 32) as u32) < 7 || count_different_digits(s as u32) < 7 {
+        return Err(SeedError::WeakSeed);
+    }
+
+    Ok(())
+}
+
+// Returns the number of different hex digits.
+const fn count_different_digits(n: u32) -> u32 {
+    let mut c: u32 = 0;
+    let mut i = 0;
+    while i < 32 {
+        c |= 1 << ((n >> i) & 0xf);
+        i += 4;
+    }
+
+    c.count_ones()
+}
+
+// The generator used to produce seeds, positioned at output `n` of the base table.
+const fn seed_rand(table: &[u64], n: u64) -> Rand {
+    let len = table.len() as u64;
+    let mut r: u64 = n / 100_000_000;
+    let t: u64 = n % 100_000_000;
+    let s = table[(r % len) as usize];
+    r /= len;
+    let mut rand = Rand { s, x: 0, w: 0 };
+    rand.jump(t.wrapping_add(r.wrapping_mul(100_000_000)));
+    rand
+}
+
+// Builds an odd Weyl constant with different hex digits in the upper and lower 32 bits.
+const fn weyl_constant(rand: &mut Rand) -> u64 {
+    ((different_digits(rand) as u64) << 32) | (different_digits(rand) as u64) | 1
+}
+
+const fn different_digits(rand: &mut Rand) -> u32 {
     let mut m: u32 = 0;
     let mut a: u32 = 0;
     let mut c: u32 = 0;
@@ -165,6 +492,163 @@ mod tests {
         assert_eq!(r.rand(), 0x212dbe1a);
     }
 
+    #[test]
+    fn test_clone() {
+        let mut a = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        a.rand();
+        let mut b = a.clone();
+
+        assert_eq!(a, b);
+        assert_eq!(a.rand(), b.rand());
+        b.rand();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_rand_u64() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        assert_eq!(r.rand_u64(), 0xdf4ee85c_b5ad4ece);
+        assert_eq!(r.rand_u64(), 0xc6dcbccf_1889155f);
+    }
+
+    #[test]
+    fn test_even_seed() {
+        assert_eq!(
+            Rand::new(0xb5ad4eceda1ce2a8).err(),
+            Some(SeedError::EvenSeed)
+        );
+    }
+
+    #[test]
+    fn test_validate_seed() {
+        assert_eq!(validate_seed(0xb5ad4eceda1ce2a9), Ok(()));
+        assert_eq!(validate_seed(0xb5ad4eceda1ce2a8), Err(SeedError::EvenSeed));
+        assert_eq!(validate_seed(1), Err(SeedError::WeakSeed));
+        assert_eq!(validate_seed(0xffffffffffffffff), Err(SeedError::WeakSeed));
+        // Two repeated digits in the upper half
+        assert_eq!(validate_seed(0xb5ad4ebeda1ce2a9), Err(SeedError::WeakSeed));
+        // Two repeated digits in the lower half
+        assert_eq!(validate_seed(0xb5ad4eceda1ca2a9), Err(SeedError::WeakSeed));
+
+        for n in 0..10_000 {
+            assert_eq!(validate_seed(seed(n)), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_new_checked() {
+        assert!(Rand::new_checked(seed(0)).is_ok());
+        assert_eq!(Rand::new_checked(2).err(), Some(SeedError::EvenSeed));
+        assert_eq!(Rand::new_checked(3).err(), Some(SeedError::WeakSeed));
+    }
+
+    #[test]
+    fn test_discard() {
+        let mut a = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut b = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        a.discard(0);
+        assert_eq!(a.rand(), 0xb5ad4ece);
+
+        a.discard(7);
+        for _ in 0..9 {
+            b.rand();
+        }
+        assert_eq!(a.rand(), 0x7294da52);
+        assert_eq!(a.rand(), b.rand());
+        assert_eq!(a.rand(), b.rand());
+    }
+
+    #[test]
+    fn test_jump() {
+        let mut a = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut b = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        a.jump(1000);
+        b.discard(1000);
+        assert_eq!(a.w, b.w);
+        assert_eq!(a.x, a.w);
+
+        // Jumps compose
+        let mut c = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        c.jump(400);
+        c.jump(600);
+        assert_eq!(c.rand(), a.rand());
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut a = Rand::stream(1);
+        let mut b = Rand::new(seed(1)).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(a.rand(), b.rand());
+        }
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn test_from_str_seed() {
+        assert_eq!(Rand::from_str_seed("").s, 0x4f27a0695d69a8ed);
+        assert_eq!(
+            Rand::from_str_seed("checkout-flow/regression-17").s,
+            0xdf6205bca1e0f8c9
+        );
+        assert_eq!(
+            Rand::from_str_seed("checkout-flow/regression-18").s,
+            0xc2f0b3e1930b714d
+        );
+        assert_eq!(
+            Rand::from_bytes_seed(&[0xff, 0x00]),
+            Rand::stream(fnv1a(&[0xff, 0x00]))
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let mut parent = Rand::stream(0);
+        let a = parent.split();
+        let b = parent.split();
+
+        assert_eq!(a.s & 1, 1);
+        assert_ne!(a.s, b.s);
+        assert_ne!(a.s, parent.s);
+
+        // Deterministic
+        let mut other = Rand::stream(0);
+        assert_eq!(other.split().s, a.s);
+        assert_eq!(other.split().s, b.s);
+    }
+
+    #[test]
+    fn test_const() {
+        const TABLE: [u32; 3] = const_fill(0xb5ad4eceda1ce2a9);
+        const SEED: u64 = seed(0);
+        const VALID: Result<(), SeedError> = validate_seed(SEED);
+        const FIRST: u32 = {
+            let mut r = Rand::stream(0);
+            r.rand()
+        };
+
+        assert_eq!(TABLE, [0xb5ad4ece, 0xdf4ee85c, 0x1889155f]);
+        assert_eq!(SEED, 0x8b5ad4ceb9c1fe73);
+        assert_eq!(VALID, Ok(()));
+        assert_eq!(FIRST, 0x8b5ad4ce);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_const_fill_even_seed() {
+        let _: [u32; 1] = const_fill(2);
+    }
+
     #[test]
     fn test_seed() {
         assert_eq!(seed(0), 0x8b5ad4ceb9c1fe73);
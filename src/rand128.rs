@@ -0,0 +1,102 @@
+use crate::SeedError;
+use core::result::Result;
+
+/// 128-bit state variant of the generator (`msws128`).
+///
+/// The Weyl sequence has a period of 2^128, the output is the middle 64 bits of the square.
+///
+/// ```
+/// use msws::{seed128, Rand128};
+///
+/// let mut r = Rand128::new(seed128(0)).expect("invalid seed");
+/// r.rand();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rand128 {
+    // Seed, must be odd
+    s: u128,
+    // Random output
+    x: u128,
+    // Weyl sequence
+    w: u128,
+}
+
+impl Rand128 {
+    /// Generates a new Rand128 struct from an *odd* seed.
+    pub const fn new(s: u128) -> Result<Self, SeedError> {
+        if s & 1 == 0 {
+            return Err(SeedError::EvenSeed);
+        }
+
+        Ok(Self { s, x: 0, w: 0 })
+    }
+
+    /// Returns a random 64-bit integer.
+    pub const fn rand(&mut self) -> u64 {
+        // Square the number
+        self.x = self.x.wrapping_pow(2);
+
+        // Update the Weyl sequence
+        self.w = self.w.wrapping_add(self.s);
+
+        // Apply to x
+        self.x = self.x.wrapping_add(self.w);
+
+        // Store the middle 64-bits
+        self.x = self.x.rotate_left(64);
+
+        self.x as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seed128;
+
+    #[test]
+    fn test_rand() {
+        let mut r = Rand128::new(0x8b5ad4ceb9c1fe73_64d098b5c4f26d37).unwrap();
+
+        assert_eq!(r.rand(), 0x8b5ad4ceb9c1fe73);
+        assert_eq!(r.rand(), 0xdd7386f670f391a1);
+        assert_eq!(r.rand(), 0x1886cd7869dc1a09);
+        assert_eq!(r.rand(), 0xd8814c5c502be774);
+        assert_eq!(r.rand(), 0xcb56213e2c6552bf);
+        assert_eq!(r.rand(), 0xa7d155d9c7a295c8);
+        assert_eq!(r.rand(), 0xfa5d0f9ef346ff11);
+        assert_eq!(r.rand(), 0x72b4c3ca3917ca33);
+        assert_eq!(r.rand(), 0x9fb273a2b7a698c6);
+        assert_eq!(r.rand(), 0x54ab37eecd245f80);
+    }
+
+    #[test]
+    fn test_even_seed() {
+        assert_eq!(Rand128::new(2).err(), Some(SeedError::EvenSeed));
+    }
+
+    #[test]
+    fn test_seed128_matches_seed() {
+        // The upper 64 bits are drawn the same way as seed()
+        assert_eq!(seed128(0), 0x8b5ad4ceb9c1fe73_648b2ae1f31c2e09);
+        assert_eq!((seed128(1) >> 64) as u64 | 1, crate::seed(1));
+    }
+
+    #[test]
+    fn test_seed128() {
+        for n in 0..1000 {
+            let s = seed128(n);
+            assert_eq!(s & 1, 1);
+            // The upper words have 8 different hex digits, the lowest may repeat one
+            // after being made odd
+            for word in 0..4 {
+                let w = (s >> (word * 32)) as u32;
+                let mut digits = 0u16;
+                for i in 0..8 {
+                    digits |= 1 << ((w >> (i * 4)) & 0xf);
+                }
+                assert!(digits.count_ones() >= if word == 0 { 7 } else { 8 });
+            }
+        }
+    }
+}
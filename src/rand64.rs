@@ -0,0 +1,97 @@
+use crate::SeedError;
+use core::result::Result;
+
+/// 64-bit output variant of the generator (`msws64`).
+///
+/// Two middle square Weyl sequences are interleaved and the unrotated state of the first is
+/// XORed with the rotated state of the second, giving 64 random bits per call.
+///
+/// ```
+/// use msws::Rand64;
+///
+/// let mut r = Rand64::new(0xb5ad4eceda1ce2a9, 0x278c5a4d8419fe6b).expect("invalid seed");
+/// r.rand(); // => 0x31b4b0a5fd90b8e4
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rand64 {
+    // Seeds, must be odd
+    s1: u64,
+    s2: u64,
+    // Random output
+    x1: u64,
+    x2: u64,
+    // Weyl sequences
+    w1: u64,
+    w2: u64,
+}
+
+impl Rand64 {
+    /// Generates a new Rand64 struct from two *odd* seeds.
+    ///
+    /// The seeds should be different from each other, `seed()` can be used to produce them.
+    pub const fn new(s1: u64, s2: u64) -> Result<Self, SeedError> {
+        if s1 & 1 == 0 || s2 & 1 == 0 {
+            return Err(SeedError::EvenSeed);
+        }
+
+        Ok(Self {
+            s1,
+            s2,
+            x1: 0,
+            x2: 0,
+            w1: 0,
+            w2: 0,
+        })
+    }
+
+    /// Returns a random 64-bit integer.
+    pub const fn rand(&mut self) -> u64 {
+        // First generator, keep the unrotated value for the output
+        self.x1 = self.x1.wrapping_pow(2);
+        self.w1 = self.w1.wrapping_add(self.s1);
+        self.x1 = self.x1.wrapping_add(self.w1);
+        let xx = self.x1;
+        self.x1 = self.x1.rotate_left(32);
+
+        // Second generator
+        self.x2 = self.x2.wrapping_pow(2);
+        self.w2 = self.w2.wrapping_add(self.s2);
+        self.x2 = self.x2.wrapping_add(self.w2);
+        self.x2 = self.x2.rotate_left(32);
+
+        xx ^ self.x2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rand() {
+        let mut r = Rand64::new(0xb5ad4eceda1ce2a9, 0x278c5a4d8419fe6b).unwrap();
+
+        assert_eq!(r.rand(), 0x31b4b0a5fd90b8e4);
+        assert_eq!(r.rand(), 0xbd08dfa36824fe79);
+        assert_eq!(r.rand(), 0xf895643d60a55706);
+        assert_eq!(r.rand(), 0x644e3a9a663281d9);
+        assert_eq!(r.rand(), 0x642616e2d6d74b35);
+        assert_eq!(r.rand(), 0x5c3ac8f51a20305c);
+        assert_eq!(r.rand(), 0xd90648f19f25d755);
+        assert_eq!(r.rand(), 0xb1788103945cec2f);
+        assert_eq!(r.rand(), 0xc9d91df5596c0239);
+        assert_eq!(r.rand(), 0x08c4143ddfa78f4c);
+    }
+
+    #[test]
+    fn test_even_seed() {
+        assert_eq!(
+            Rand64::new(0xb5ad4eceda1ce2a8, 0x278c5a4d8419fe6b).err(),
+            Some(SeedError::EvenSeed)
+        );
+        assert_eq!(
+            Rand64::new(0xb5ad4eceda1ce2a9, 0x278c5a4d8419fe6a).err(),
+            Some(SeedError::EvenSeed)
+        );
+    }
+}
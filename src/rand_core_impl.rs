@@ -0,0 +1,92 @@
+//! [`rand_core`] trait implementations, enabled with the `rand_core` feature.
+
+use crate::{seed, Rand};
+use rand_core::{Error, RngCore, SeedableRng};
+
+impl RngCore for Rand {
+    fn next_u32(&mut self) -> u32 {
+        self.rand()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rand_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        Rand::fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        Rand::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for Rand {
+    type Seed = [u8; 8];
+
+    /// The seed is read as a little-endian integer and passed through
+    /// [`seed`], so every input yields a valid (odd) Weyl constant.
+    fn from_seed(bytes: Self::Seed) -> Self {
+        Self::seed_from_u64(u64::from_le_bytes(bytes))
+    }
+
+    /// Equivalent to `Rand::new(seed(n))`.
+    fn seed_from_u64(n: u64) -> Self {
+        Rand::new(seed(n)).expect("seed() always returns an odd seed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_u32() {
+        let mut a = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut b = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(a.next_u32(), b.rand());
+        }
+    }
+
+    #[test]
+    fn test_next_u64() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        // Low word first
+        assert_eq!(r.next_u64(), 0xdf4ee85c_b5ad4ece);
+        assert_eq!(r.next_u64(), 0xc6dcbccf_1889155f);
+    }
+
+    #[test]
+    fn test_fill_bytes() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut buf = [0u8; 6];
+        r.fill_bytes(&mut buf);
+
+        assert_eq!(buf, [0xce, 0x4e, 0xad, 0xb5, 0x5c, 0xe8]);
+    }
+
+    #[test]
+    fn test_from_seed() {
+        let mut a = Rand::from_seed(1u64.to_le_bytes());
+        let mut b = Rand::new(seed(1)).unwrap();
+        assert_eq!(a.rand(), b.rand());
+
+        // Even and large inputs are mixed through seed()
+        for n in [0, 2, u64::MAX - 1, u64::MAX].iter() {
+            let mut r = Rand::from_seed(n.to_le_bytes());
+            let mut expected = Rand::new(seed(*n)).unwrap();
+            assert_eq!(r.rand(), expected.rand());
+        }
+    }
+
+    #[test]
+    fn test_seed_from_u64() {
+        let mut a = Rand::seed_from_u64(0);
+        let mut b = Rand::new(0x8b5ad4ceb9c1fe73).unwrap();
+        assert_eq!(a.rand(), b.rand());
+    }
+}
@@ -0,0 +1,210 @@
+use crate::Rand;
+use core::ops::{Range, RangeInclusive};
+
+/// A range that `Rand::range()` can sample from.
+///
+/// Implemented for `Range` and `RangeInclusive` of `u32`, `i32`, `u64`, `i64` and `usize`.
+/// 32-bit types use one `rand()` output per attempt and 64-bit types use `rand_u64()`.
+/// `usize` is always sampled as a 64-bit type so results are the same on every platform.
+pub trait SampleRange<T> {
+    /// Returns a random value in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    fn sample(self, rand: &mut Rand) -> T;
+}
+
+impl Rand {
+    /// Returns a random integer in `0..n`, without bias.
+    ///
+    /// Uses Lemire's nearly divisionless method: a division is only needed when the first
+    /// output falls in the small biased region, which is then rejected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::new(0xb5ad4eceda1ce2a9).expect("invalid seed");
+    /// r.below(6); // => 4
+    /// ```
+    pub fn below(&mut self, n: u32) -> u32 {
+        assert!(n > 0, "n must be greater than 0");
+
+        let mut m = (self.rand() as u64) * (n as u64);
+        if (m as u32) < n {
+            let t = n.wrapping_neg() % n;
+            while (m as u32) < t {
+                m = (self.rand() as u64) * (n as u64);
+            }
+        }
+
+        (m >> 32) as u32
+    }
+
+    /// Returns a random integer in `0..n`, without bias, using `rand_u64()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn below_u64(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "n must be greater than 0");
+
+        let mut m = (self.rand_u64() as u128) * (n as u128);
+        if (m as u64) < n {
+            let t = n.wrapping_neg() % n;
+            while (m as u64) < t {
+                m = (self.rand_u64() as u128) * (n as u128);
+            }
+        }
+
+        (m >> 64) as u64
+    }
+
+    /// Returns a random integer in `range`, without bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::new(0xb5ad4eceda1ce2a9).expect("invalid seed");
+    /// r.range(1..=6); // => 5
+    /// r.range(-10i64..10); // => -9
+    /// ```
+    pub fn range<T, R: SampleRange<T>>(&mut self, range: R) -> T {
+        range.sample(self)
+    }
+}
+
+macro_rules! impl_sample_range {
+    ($ty:ty, $unsigned:ty, $below:ident, $full:ident) => {
+        impl SampleRange<$ty> for Range<$ty> {
+            fn sample(self, rand: &mut Rand) -> $ty {
+                assert!(self.start < self.end, "cannot sample empty range");
+
+                let span = self.end.wrapping_sub(self.start) as $unsigned;
+                self.start.wrapping_add(rand.$below(span) as $ty)
+            }
+        }
+
+        impl SampleRange<$ty> for RangeInclusive<$ty> {
+            fn sample(self, rand: &mut Rand) -> $ty {
+                let (start, end) = self.into_inner();
+                assert!(start <= end, "cannot sample empty range");
+
+                let span = (end.wrapping_sub(start) as $unsigned).wrapping_add(1);
+                if span == 0 {
+                    // The full range of the type
+                    return rand.$full() as $ty;
+                }
+                start.wrapping_add(rand.$below(span) as $ty)
+            }
+        }
+    };
+}
+
+impl_sample_range!(u32, u32, below, rand);
+impl_sample_range!(i32, u32, below, rand);
+impl_sample_range!(u64, u64, below_u64, rand_u64);
+impl_sample_range!(i64, u64, below_u64, rand_u64);
+impl_sample_range!(usize, u64, below_u64, rand_u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_below() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        assert_eq!(r.below(6), 4);
+        assert_eq!(r.below(6), 5);
+        assert_eq!(r.below(6), 0);
+        assert_eq!(r.below(6), 4);
+    }
+
+    #[test]
+    fn test_below_edge_cases() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        for _ in 0..100 {
+            assert_eq!(r.below(1), 0);
+            assert_eq!(r.below_u64(1), 0);
+            assert!(r.below(2) < 2);
+            assert!(r.below(u32::MAX) < u32::MAX);
+            assert!(r.below_u64(u64::MAX) < u64::MAX);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_below_zero() {
+        Rand::new(0xb5ad4eceda1ce2a9).unwrap().below(0);
+    }
+
+    #[test]
+    fn test_below_uniform() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            counts[r.below(6) as usize] += 1;
+        }
+        for c in counts.iter() {
+            assert!(*c > 9_700 && *c < 10_300, "{:?}", counts);
+        }
+
+        let mut counts = [0u32; 7];
+        for _ in 0..70_000 {
+            counts[r.below_u64(7) as usize] += 1;
+        }
+        for c in counts.iter() {
+            assert!(*c > 9_700 && *c < 10_300, "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        assert_eq!(r.range(1..=6), 5);
+        assert_eq!(r.range(-10i64..10), -9);
+
+        for _ in 0..1000 {
+            let n = r.range(-3i32..3);
+            assert!((-3..3).contains(&n));
+            let n = r.range(10u64..=12);
+            assert!((10..=12).contains(&n));
+            let n = r.range(5usize..6);
+            assert_eq!(n, 5);
+            let n = r.range(i64::MIN..i64::MIN + 2);
+            assert!(n == i64::MIN || n == i64::MIN + 1);
+        }
+    }
+
+    #[test]
+    fn test_range_full() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut other = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        assert_eq!(r.range(0..=u32::MAX), other.rand());
+        assert_eq!(r.range(i32::MIN..=i32::MAX), other.rand() as i32);
+        assert_eq!(r.range(0..=u64::MAX), other.rand_u64());
+        assert_eq!(r.range(i64::MIN..=i64::MAX), other.rand_u64() as i64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_empty() {
+        Rand::new(0xb5ad4eceda1ce2a9).unwrap().range(3u32..3);
+    }
+}
@@ -0,0 +1,264 @@
+//! Sampling without replacement.
+//!
+//! `sample_indices()` picks `k` distinct indices out of `n` with Floyd's algorithm, using
+//! exactly `k` random numbers. `reservoir_sample()` picks `k` items from an iterator of
+//! unknown length in a single pass. Both have array versions that don't allocate, and
+//! `reservoir_sample_into()` fills a caller-supplied slice.
+//!
+//! # Example
+//!
+//! ```
+//! use msws::{sample, Rand};
+//!
+//! let mut r = Rand::stream(0);
+//! let tests: [usize; 3] = sample::sample_indices_array(&mut r, 100);
+//!
+//! let mut picked = [0; 2];
+//! let n = sample::reservoir_sample_into(1..=10, &mut picked, &mut r);
+//! assert_eq!(n, 2);
+//! ```
+
+use crate::Rand;
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeSet, vec::Vec};
+
+/// Returns `k` distinct indices from `0..n` in ascending order, enabled with the `alloc`
+/// feature.
+///
+/// # Panics
+///
+/// Panics if `k > n`.
+#[cfg(feature = "alloc")]
+pub fn sample_indices(rand: &mut Rand, n: usize, k: usize) -> Vec<usize> {
+    assert!(k <= n, "cannot sample {} indices from {}", k, n);
+
+    let mut chosen = BTreeSet::new();
+    for j in n - k..n {
+        let t = rand.below_usize(j + 1);
+        if !chosen.insert(t) {
+            // Already chosen, j can't have been
+            chosen.insert(j);
+        }
+    }
+
+    chosen.into_iter().collect()
+}
+
+/// Returns `K` distinct indices from `0..n` in ascending order.
+///
+/// This takes O(K²) time, so prefer `sample_indices()` when `K` is large.
+///
+/// # Panics
+///
+/// Panics if `K > n`.
+pub fn sample_indices_array<const K: usize>(rand: &mut Rand, n: usize) -> [usize; K] {
+    let mut indices = [0; K];
+    floyd(rand, n, &mut indices);
+    indices
+}
+
+/// Returns up to `k` items chosen uniformly from `iter`, enabled with the `alloc` feature.
+///
+/// Fewer than `k` items are returned if the iterator is shorter. The items are in the
+/// reservoir's order, which isn't random: shuffle them if the order matters.
+#[cfg(feature = "alloc")]
+pub fn reservoir_sample<I: IntoIterator>(iter: I, k: usize, rand: &mut Rand) -> Vec<I::Item> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut iter = iter.into_iter();
+    let mut reservoir: Vec<I::Item> = iter.by_ref().take(k).collect();
+    if reservoir.len() < k {
+        return reservoir;
+    }
+
+    for (i, item) in iter.enumerate() {
+        let j = rand.below_usize(k + i + 1);
+        if j < k {
+            reservoir[j] = item;
+        }
+    }
+
+    reservoir
+}
+
+/// Fills `buf` with items chosen uniformly from `iter` and returns how many were written.
+///
+/// This is the same as `reservoir_sample()` with `k` being the length of `buf`.
+pub fn reservoir_sample_into<I: IntoIterator>(
+    iter: I,
+    buf: &mut [I::Item],
+    rand: &mut Rand,
+) -> usize {
+    let k = buf.len();
+    if k == 0 {
+        return 0;
+    }
+
+    let mut iter = iter.into_iter();
+
+    for (i, slot) in buf.iter_mut().enumerate() {
+        match iter.next() {
+            Some(item) => *slot = item,
+            None => return i,
+        }
+    }
+
+    for (i, item) in iter.enumerate() {
+        let j = rand.below_usize(k + i + 1);
+        if j < k {
+            buf[j] = item;
+        }
+    }
+
+    k
+}
+
+/// Returns up to `K` items chosen uniformly from `iter`, and how many were chosen.
+///
+/// This is the same as `reservoir_sample_into()` with a buffer of `K` default values. Slots
+/// past the returned count are left as `Default::default()`.
+pub fn reservoir_sample_array<I, const K: usize>(iter: I, rand: &mut Rand) -> ([I::Item; K], usize)
+where
+    I: IntoIterator,
+    I::Item: Default,
+{
+    let mut buf = core::array::from_fn(|_| Default::default());
+    let n = reservoir_sample_into(iter, &mut buf, rand);
+
+    (buf, n)
+}
+
+// Fills `out` with distinct indices from `0..n` using Floyd's algorithm, keeping them sorted
+// so membership can be checked with a binary search. Each insertion shifts the larger indices
+// up, which is fine for the small arrays this is used for.
+fn floyd(rand: &mut Rand, n: usize, out: &mut [usize]) {
+    let k = out.len();
+    assert!(k <= n, "cannot sample {} indices from {}", k, n);
+
+    for (len, j) in (n - k..n).enumerate() {
+        let t = rand.below_usize(j + 1);
+        let chosen = &mut out[..=len];
+        let x = match chosen[..len].binary_search(&t) {
+            // Already chosen, j can't have been
+            Ok(_) => j,
+            Err(_) => t,
+        };
+        let pos = chosen[..len].binary_search(&x).unwrap_err();
+        chosen.copy_within(pos..len, pos + 1);
+        chosen[pos] = x;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_indices_array() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        let indices: [usize; 5] = sample_indices_array(&mut r, 10);
+        assert_eq!(indices, [0, 4, 6, 8, 9]);
+
+        let all: [usize; 10] = sample_indices_array(&mut r, 10);
+        assert_eq!(all, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let none: [usize; 0] = sample_indices_array(&mut r, 0);
+        assert_eq!(none, []);
+    }
+
+    #[test]
+    fn test_sample_indices_uniform() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut counts = [0u32; 10];
+        for _ in 0..30_000 {
+            let indices: [usize; 3] = sample_indices_array(&mut r, 10);
+            assert!(indices[0] < indices[1] && indices[1] < indices[2]);
+            for i in indices.iter() {
+                counts[*i] += 1;
+            }
+        }
+
+        for c in counts.iter() {
+            assert!(*c > 8_700 && *c < 9_300, "{:?}", counts);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_indices_too_many() {
+        let _: [usize; 3] = sample_indices_array(&mut Rand::new(0xb5ad4eceda1ce2a9).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_reservoir_sample_into() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        let mut buf = [0; 3];
+        assert_eq!(reservoir_sample_into(0..100, &mut buf, &mut r), 3);
+        assert_eq!(buf, [96, 80, 20]);
+
+        let mut buf = [0; 3];
+        assert_eq!(reservoir_sample_into(0..2, &mut buf, &mut r), 2);
+        assert_eq!(buf, [0, 1, 0]);
+
+        // Never consumes the iterator
+        assert_eq!(reservoir_sample_into(0.., &mut [], &mut r), 0);
+    }
+
+    #[test]
+    fn test_reservoir_sample_array() {
+        let mut a = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut b = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        let mut buf = [0; 3];
+        reservoir_sample_into(0..100, &mut buf, &mut a);
+        assert_eq!(reservoir_sample_array(0..100, &mut b), (buf, 3));
+
+        let short: ([char; 3], usize) = reservoir_sample_array("ab".chars(), &mut b);
+        assert_eq!(short, (['a', 'b', '\0'], 2));
+
+        let none: ([u32; 0], usize) = reservoir_sample_array(0.., &mut b);
+        assert_eq!(none, ([], 0));
+    }
+
+    #[test]
+    fn test_reservoir_sample_uniform() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut counts = [0u32; 10];
+        for _ in 0..30_000 {
+            let mut buf = [0; 3];
+            reservoir_sample_into(0..10, &mut buf, &mut r);
+            for i in buf.iter() {
+                counts[*i] += 1;
+            }
+        }
+
+        for c in counts.iter() {
+            assert!(*c > 8_700 && *c < 9_300, "{:?}", counts);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_alloc() {
+        let mut a = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut b = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+
+        let indices: [usize; 5] = sample_indices_array(&mut a, 10);
+        assert_eq!(sample_indices(&mut b, 10, 5), indices);
+
+        let mut buf = [0; 3];
+        reservoir_sample_into(0..100, &mut buf, &mut a);
+        assert_eq!(reservoir_sample(0..100, 3, &mut b), buf);
+        assert_eq!(reservoir_sample(0..2, 3, &mut b), [0, 1]);
+        assert!(reservoir_sample(0.., 0, &mut b).is_empty());
+
+        let indices = sample_indices(&mut b, 1_000_000, 500_000);
+        assert_eq!(indices.len(), 500_000);
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+    }
+}
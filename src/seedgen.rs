@@ -0,0 +1,122 @@
+//! Generation of base constant tables, like the one used by `seed()`.
+//!
+//! `seed()` maps an integer to one of 30 base constants, each of which provides 100 million
+//! seeds. This module generates new tables of base constants from any odd seed, and produces
+//! seeds from them.
+//!
+//! Both halves of each constant in the built-in table are `different_digits()` outputs of a
+//! generator seeded with [`BASE`]. Candidate `c` pairs output `c` as the upper 32 bits with
+//! output `LOWER_OFFSET + c` as the lower 32 bits, then sets the low bit. The built-in table
+//! keeps 30 of the first 114 candidates; the rule used to skip the others wasn't published.
+//! `generate()` builds constants the same way and keeps every candidate, so its first three
+//! constants for [`BASE`] match the built-in table.
+//!
+//! # Example
+//!
+//! ```
+//! use msws::{seedgen, Rand};
+//!
+//! let mut table = [0; 8];
+//! seedgen::generate(0x278c5a4d8419fe6b, &mut table).expect("invalid seed");
+//!
+//! let mut r = Rand::new(seedgen::seed(&table, 42)).expect("invalid seed");
+//! r.rand();
+//! ```
+
+use crate::{different_digits, seed_rand, weyl_constant, Rand, SeedError};
+use core::result::Result;
+
+/// The seed of the generator that produced the built-in table.
+pub const BASE: u64 = 0xb5ad4eceda1ce2a9;
+
+/// The number of `different_digits()` outputs between the upper and lower half of a constant.
+pub const LOWER_OFFSET: usize = 1_000_979;
+
+/// Fills `table` with base constants derived from an *odd* seed.
+///
+/// Each constant has different hex digits in the upper and lower 32 bits, the same as the
+/// seeds returned by `seed()`. The lower halves come from a second generator that skips the
+/// first [`LOWER_OFFSET`] outputs, so this takes a few milliseconds regardless of table size.
+pub fn generate(base: u64, table: &mut [u64]) -> Result<(), SeedError> {
+    let mut upper = Rand::new(base)?;
+    let mut lower = Rand::new(base)?;
+    for _ in 0..LOWER_OFFSET {
+        different_digits(&mut lower);
+    }
+
+    for s in table.iter_mut() {
+        *s = ((different_digits(&mut upper) as u64) << 32)
+            | (different_digits(&mut lower) as u64)
+            | 1;
+    }
+
+    Ok(())
+}
+
+/// Returns a seed for a given integer using a table of base constants.
+///
+/// `seed(n)` is equivalent to calling this with the built-in table. Each constant provides 100
+/// million seeds before moving on to the next one.
+///
+/// # Panics
+///
+/// Panics if `table` is empty.
+pub const fn seed(table: &[u64], n: u64) -> u64 {
+    weyl_constant(&mut seed_rand(table, n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{validate_seed, S};
+
+    #[test]
+    fn test_builtin_table() {
+        // The candidates kept by the built-in table, out of the first 114
+        const KEPT: [usize; 30] = [
+            0, 1, 2, 4, 5, 6, 8, 10, 11, 12, 15, 16, 20, 21, 22, 25, 33, 39, 40, 43, 53, 57, 58,
+            62, 66, 77, 88, 109, 110, 113,
+        ];
+
+        let mut table = [0; 114];
+        generate(BASE, &mut table).unwrap();
+
+        for (s, c) in S.iter().zip(KEPT.iter()) {
+            assert_eq!(*s, table[*c]);
+        }
+        assert_eq!(table[..3], S[..3]);
+    }
+
+    #[test]
+    fn test_seed() {
+        for n in [0, 1, 99_999_999, 100_000_000, 2_999_999_999, 3_000_000_000].iter() {
+            assert_eq!(seed(&S, *n), crate::seed(*n));
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut table = [0; 30];
+        generate(0x278c5a4d8419fe6b, &mut table).unwrap();
+
+        assert_eq!(table[0], 0x278c5a4d_92d5e0c1);
+        for s in table.iter() {
+            assert_eq!(validate_seed(*s), Ok(()));
+        }
+
+        let mut other = [0; 30];
+        generate(0x278c5a4d8419fe6b, &mut other).unwrap();
+        assert_eq!(table, other);
+
+        assert_eq!(
+            generate(0x278c5a4d8419fe6a, &mut table),
+            Err(SeedError::EvenSeed)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_seed_empty_table() {
+        seed(&[], 0);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::Rand;
+
+impl Rand {
+    /// Shuffles `slice` in place with the Fisher–Yates algorithm.
+    ///
+    /// Each position is picked without bias using one `below()` call, so the permutation for a
+    /// given generator state is the same on every platform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use msws::Rand;
+    ///
+    /// let mut r = Rand::new(0xb5ad4eceda1ce2a9).expect("invalid seed");
+    /// let mut cards = [1, 2, 3, 4, 5];
+    /// r.shuffle(&mut cards); // => [3, 2, 1, 5, 4]
+    /// ```
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.below_usize(i + 1);
+            slice.swap(i, j);
+        }
+    }
+
+    /// Moves `k` randomly chosen elements to the start of `slice` in random order.
+    ///
+    /// Returns the chosen elements and the rest, which are left in an unspecified order. `k` is
+    /// capped at the length of the slice. Only `k` random numbers are used.
+    pub fn partial_shuffle<'a, T>(
+        &mut self,
+        slice: &'a mut [T],
+        k: usize,
+    ) -> (&'a mut [T], &'a mut [T]) {
+        let len = slice.len();
+        let k = k.min(len);
+        for i in 0..k {
+            let j = i + self.below_usize(len - i);
+            slice.swap(i, j);
+        }
+
+        slice.split_at_mut(k)
+    }
+
+    /// Returns a random element of `slice`, or `None` if it's empty.
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        Some(&slice[self.below_usize(slice.len())])
+    }
+
+    /// Returns a mutable reference to a random element of `slice`, or `None` if it's empty.
+    pub fn choose_mut<'a, T>(&mut self, slice: &'a mut [T]) -> Option<&'a mut T> {
+        if slice.is_empty() {
+            return None;
+        }
+
+        let i = self.below_usize(slice.len());
+        Some(&mut slice[i])
+    }
+
+    // Returns an index in `0..n`. Lengths that fit in 32 bits use one `rand()` output, so
+    // results don't depend on the size of `usize`.
+    pub(crate) fn below_usize(&mut self, n: usize) -> usize {
+        if n as u64 <= u32::MAX as u64 {
+            self.below(n as u32) as usize
+        } else {
+            self.below_u64(n as u64) as usize
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuffle() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut v = [1, 2, 3, 4, 5];
+        r.shuffle(&mut v);
+        assert_eq!(v, [3, 2, 1, 5, 4]);
+
+        let mut v = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        r.shuffle(&mut v);
+        assert_eq!(v, [4, 3, 7, 5, 6, 8, 9, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_shuffle_small() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut empty: [u8; 0] = [];
+        r.shuffle(&mut empty);
+        let mut one = [1];
+        r.shuffle(&mut one);
+        assert_eq!(one, [1]);
+
+        // No random numbers are used for fewer than 2 elements
+        assert_eq!(r.rand(), 0xb5ad4ece);
+    }
+
+    #[test]
+    fn test_shuffle_uniform() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            let mut v = [0, 1, 2];
+            r.shuffle(&mut v);
+            let i = match v {
+                [0, 1, 2] => 0,
+                [0, 2, 1] => 1,
+                [1, 0, 2] => 2,
+                [1, 2, 0] => 3,
+                [2, 0, 1] => 4,
+                _ => 5,
+            };
+            counts[i] += 1;
+        }
+
+        for c in counts.iter() {
+            assert!(*c > 9_700 && *c < 10_300, "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_partial_shuffle() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let mut v = [1, 2, 3, 4, 5];
+        let (chosen, rest) = r.partial_shuffle(&mut v, 2);
+        assert_eq!(chosen, [4, 5]);
+        assert_eq!(rest.len(), 3);
+
+        let mut v = [1, 2, 3];
+        let (chosen, rest) = r.partial_shuffle(&mut v, 10);
+        assert_eq!(chosen.len(), 3);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_choose() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        let empty: [u8; 0] = [];
+
+        assert_eq!(r.choose(&empty), None);
+        assert_eq!(r.choose(&[1, 2, 3, 4, 5]), Some(&4));
+
+        let mut v = [1, 2, 3];
+        *r.choose_mut(&mut v).unwrap() = 10;
+        assert_eq!(v, [1, 2, 10]);
+    }
+}
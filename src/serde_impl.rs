@@ -0,0 +1,75 @@
+//! Support for deserializing `Rand`, enabled with the `serde` feature.
+
+use crate::{Rand, SeedError};
+use core::convert::TryFrom;
+use serde::Deserialize;
+
+// The serialized form of `Rand`, checked before being turned back into one.
+#[derive(Deserialize)]
+#[serde(rename = "Rand")]
+pub(crate) struct RandState {
+    s: u64,
+    x: u64,
+    w: u64,
+}
+
+impl TryFrom<RandState> for Rand {
+    type Error = SeedError;
+
+    fn try_from(state: RandState) -> Result<Self, SeedError> {
+        let mut rand = Rand::new(state.s)?;
+        rand.x = state.x;
+        rand.w = state.w;
+
+        Ok(rand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[test]
+    fn test_round_trip() {
+        let mut r = Rand::new(0xb5ad4eceda1ce2a9).unwrap();
+        r.rand();
+
+        assert_tokens(
+            &r,
+            &[
+                Token::Struct {
+                    name: "Rand",
+                    len: 3,
+                },
+                Token::Str("s"),
+                Token::U64(0xb5ad4eceda1ce2a9),
+                Token::Str("x"),
+                Token::U64(0xda1ce2a9b5ad4ece),
+                Token::Str("w"),
+                Token::U64(0xb5ad4eceda1ce2a9),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_even_seed() {
+        assert_de_tokens_error::<Rand>(
+            &[
+                Token::Struct {
+                    name: "Rand",
+                    len: 3,
+                },
+                Token::Str("s"),
+                Token::U64(0xb5ad4eceda1ce2a8),
+                Token::Str("x"),
+                Token::U64(0),
+                Token::Str("w"),
+                Token::U64(0),
+                Token::StructEnd,
+            ],
+            "seed must be odd",
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! [Squares][1] counter-based pseudorandom number generator.
+//!
+//! The output is a pure function of a counter and a key, so any position in a stream can be
+//! computed directly. Each key gives a stream of 2^64 outputs.
+//!
+//! [1]: https://arxiv.org/abs/2004.06278
+//!
+//! # Example
+//!
+//! ```
+//! use msws::squares;
+//!
+//! let key = squares::key(0); // => 0x8b5ad4ceb9c1fe73
+//! squares::squares32(0, key); // => 0xc8703f64
+//! squares::squares64(1_000_000, key); // => 0x10c4bcec52ae0ad0
+//! ```
+
+/// Returns a key for a given integer.
+///
+/// Keys are generated the same way as `seed()`, with different hex digits in the upper and
+/// lower 32 bits.
+pub const fn key(n: u64) -> u64 {
+    crate::seed(n)
+}
+
+/// Returns a random 32-bit integer for position `ctr` in the stream given by `key`.
+pub const fn squares32(ctr: u64, key: u64) -> u32 {
+    weyl32(ctr.wrapping_mul(key), key)
+}
+
+/// Returns a random 64-bit integer for position `ctr` in the stream given by `key`.
+pub const fn squares64(ctr: u64, key: u64) -> u64 {
+    weyl64(ctr.wrapping_mul(key), key)
+}
+
+// `squares32()` given the Weyl sequence value `y = ctr * key`.
+pub(crate) const fn weyl32(y: u64, key: u64) -> u32 {
+    let z = y.wrapping_add(key);
+    let mut x = y;
+
+    // Round 1
+    x = round(x, y);
+    // Round 2
+    x = round(x, z);
+    // Round 3
+    x = round(x, y);
+    // Round 4
+    (x.wrapping_pow(2).wrapping_add(z) >> 32) as u32
+}
+
+// `squares64()` given the Weyl sequence value `y = ctr * key`.
+pub(crate) const fn weyl64(y: u64, key: u64) -> u64 {
+    let z = y.wrapping_add(key);
+    let mut x = y;
+
+    // Round 1
+    x = round(x, y);
+    // Round 2
+    x = round(x, z);
+    // Round 3
+    x = round(x, y);
+    // Round 4
+    let t = x.wrapping_pow(2).wrapping_add(z);
+    x = t.rotate_left(32);
+    // Round 5
+    t ^ (x.wrapping_pow(2).wrapping_add(y) >> 32)
+}
+
+// Square, add and swap the upper and lower 32 bits.
+const fn round(x: u64, a: u64) -> u64 {
+    x.wrapping_pow(2).wrapping_add(a).rotate_left(32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: u64 = 0x8b5ad4ceb9c1fe73;
+
+    #[test]
+    fn test_key() {
+        assert_eq!(key(0), KEY);
+        assert_eq!(key(1), 0x64d098b5c4f26d37);
+    }
+
+    #[test]
+    fn test_squares32() {
+        assert_eq!(squares32(0, KEY), 0xc8703f64);
+        assert_eq!(squares32(1, KEY), 0x6abd34ca);
+        assert_eq!(squares32(2, KEY), 0x392813ee);
+        assert_eq!(squares32(3, KEY), 0xb76ba116);
+        assert_eq!(squares32(4, KEY), 0xe5a98f3e);
+        assert_eq!(squares32(1_000_000, KEY), 0x10c4bcec);
+    }
+
+    #[test]
+    fn test_squares64() {
+        assert_eq!(squares64(0, KEY), 0xc8703f6469ee072c);
+        assert_eq!(squares64(1, KEY), 0x6abd34ca82d088ce);
+        assert_eq!(squares64(2, KEY), 0x392813ee60873dc9);
+        assert_eq!(squares64(3, KEY), 0xb76ba116385f4358);
+        assert_eq!(squares64(4, KEY), 0xe5a98f3e03a95d61);
+        assert_eq!(squares64(1_000_000, KEY), 0x10c4bcec52ae0ad0);
+    }
+}
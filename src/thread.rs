@@ -0,0 +1,110 @@
+//! A thread-local generator with free functions, enabled with the `std` feature.
+
+use crate::{Rand, SampleRange};
+use std::cell::RefCell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+std::thread_local! {
+    // Seeded on first use
+    static THREAD_RAND: RefCell<Option<Rand>> = const { RefCell::new(None) };
+}
+
+fn with_rand<T>(f: impl FnOnce(&mut Rand) -> T) -> T {
+    THREAD_RAND.with(|rand| f(rand.borrow_mut().get_or_insert_with(new_rand)))
+}
+
+// `RandomState` keys are drawn from OS randomness, which saves depending on `getrandom`.
+fn new_rand() -> Rand {
+    Rand::stream(RandomState::new().build_hasher().finish())
+}
+
+/// Returns a random integer from the thread's generator.
+///
+/// Each thread has its own generator, seeded from OS randomness on first use unless
+/// `reseed_thread_rng()` was called first.
+///
+/// # Example
+///
+/// ```
+/// let n = msws::random_u32();
+/// let die = msws::random_range(1..=6);
+/// ```
+pub fn random_u32() -> u32 {
+    with_rand(|rand| rand.rand())
+}
+
+/// Returns a random 64-bit integer from the thread's generator, see `Rand::rand_u64()`.
+pub fn random_u64() -> u64 {
+    with_rand(|rand| rand.rand_u64())
+}
+
+/// Returns a random integer in `range` from the thread's generator, see `Rand::range()`.
+///
+/// # Panics
+///
+/// Panics if the range is empty.
+pub fn random_range<T, R: SampleRange<T>>(range: R) -> T {
+    with_rand(|rand| rand.range(range))
+}
+
+/// Returns a random float in `[0, 1)` from the thread's generator, see `Rand::next_f64()`.
+pub fn random_f64() -> f64 {
+    with_rand(|rand| rand.next_f64())
+}
+
+/// Shuffles `slice` with the thread's generator, see `Rand::shuffle()`.
+pub fn shuffle<T>(slice: &mut [T]) {
+    with_rand(|rand| rand.shuffle(slice))
+}
+
+/// Replaces the thread's generator with `Rand::stream(n)`, making the free functions
+/// deterministic on this thread.
+///
+/// # Example
+///
+/// ```
+/// msws::reseed_thread_rng(0);
+/// assert_eq!(msws::random_u32(), 0x8b5ad4ce);
+/// ```
+pub fn reseed_thread_rng(n: u64) {
+    THREAD_RAND.with(|rand| *rand.borrow_mut() = Some(Rand::stream(n)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reseed() {
+        reseed_thread_rng(0);
+        let mut r = Rand::stream(0);
+
+        assert_eq!(random_u32(), r.rand());
+        assert_eq!(random_u64(), r.rand_u64());
+        assert_eq!(random_range(0..100), r.range(0..100));
+        assert_eq!(random_f64(), r.next_f64());
+
+        let mut a = [1, 2, 3, 4, 5];
+        let mut b = a;
+        shuffle(&mut a);
+        r.shuffle(&mut b);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_threads_are_independent() {
+        reseed_thread_rng(0);
+        let first = random_u32();
+
+        // A new thread is seeded from OS randomness, not the reseeded stream
+        let other =
+            std::thread::spawn(|| (0..4).map(|_| random_u32()).collect::<std::vec::Vec<_>>())
+                .join()
+                .unwrap();
+
+        reseed_thread_rng(0);
+        assert_eq!(random_u32(), first);
+        assert_ne!(other, [first, random_u32(), random_u32(), random_u32()]);
+    }
+}